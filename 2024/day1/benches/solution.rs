@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day1::Day1);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// Two lists of `size` location ids. The ids are drawn from a small range
+/// so the right list repeats some of the left one's.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (a, b) =
+                (rng.gen_range(1..=size * 2), rng.gen_range(1..=size * 2));
+            format!("{a}   {b}\n")
+        })
+        .collect()
+}
@@ -0,0 +1,69 @@
+use common::parse::number;
+use common::{AocError, Solution};
+use itertools::Itertools;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let pairs: Vec<(usize, usize)> = input
+            .lines()
+            .map(|line| {
+                let (a, b) =
+                    line.split_whitespace().collect_tuple().ok_or_else(
+                        || AocError::at(line, "expected two location ids"),
+                    )?;
+                Ok((number(a)?, number(b)?))
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok(pairs.into_iter().unzip())
+    }
+
+    fn part1((left, right): &Self::Input<'_>) -> Result<usize, AocError> {
+        let (mut left, mut right) = (left.clone(), right.clone());
+        left.sort();
+        right.sort();
+        left.iter()
+            .zip(&right)
+            .try_fold(0usize, |sum, (a, b)| sum.checked_add(a.abs_diff(*b)))
+            .ok_or_else(|| AocError::new("the distances add up past usize"))
+    }
+
+    fn part2((left, right): &Self::Input<'_>) -> Result<usize, AocError> {
+        let counts = right.iter().counts();
+        left.iter()
+            .try_fold(0usize, |sum, a| {
+                counts
+                    .get(a)
+                    .map_or(Some(0), |&count| count.checked_mul(*a))
+                    .and_then(|score| sum.checked_add(score))
+            })
+            .ok_or_else(|| AocError::new("the similarity adds up past usize"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day1;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day1;
+        max_size: 30;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day1::{Day1, INPUT};
+
+fn main() {
+    common::run::<Day1>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,29 @@
+use common::differential::Expected;
+
+/// Pairs the smallest remaining ids one at a time, and counts every id of
+/// the left list in the right one.
+pub fn expected(input: &str) -> Expected {
+    let (mut left, mut right): (Vec<usize>, Vec<usize>) = input
+        .lines()
+        .map(|line| {
+            let mut ids = line
+                .split_whitespace()
+                .map(|id| id.parse::<usize>().unwrap());
+            (ids.next().unwrap(), ids.next().unwrap())
+        })
+        .unzip();
+    let similarity: usize = left
+        .iter()
+        .map(|a| a * right.iter().filter(|b| *b == a).count())
+        .sum();
+
+    let mut distance = 0;
+    while !left.is_empty() {
+        let take_min = |list: &mut Vec<usize>| {
+            let i = (0..list.len()).min_by_key(|&i| list[i]).unwrap();
+            list.remove(i)
+        };
+        distance += take_min(&mut left).abs_diff(take_min(&mut right));
+    }
+    Expected::both(distance, similarity)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day10::Day10);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,25 @@
+use rand::Rng;
+
+/// A map of up to `size + 1` by `size + 1` random heights with a few
+/// hiking trails from 0 to 9 carved into it, so trailheads reach some tops
+/// by several trails.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let (m, n) = (rng.gen_range(1..=size + 1), rng.gen_range(1..=size + 1));
+    let mut grid: Vec<Vec<u8>> = (0..m)
+        .map(|_| (0..n).map(|_| rng.gen_range(0..10)).collect())
+        .collect();
+    for _ in 0..rng.gen_range(0..=size) {
+        let (mut i, mut j) = (rng.gen_range(0..m), rng.gen_range(0..n));
+        for height in 0..10 {
+            grid[i][j] = height;
+            let (di, dj) = common::DIRECTIONS[rng.gen_range(0..4)];
+            i = i.saturating_add_signed(di).min(m - 1);
+            j = j.saturating_add_signed(dj).min(n - 1);
+        }
+    }
+    grid.iter()
+        .map(|row| {
+            row.iter().map(|h| char::from(b'0' + h)).collect::<String>() + "\n"
+        })
+        .collect()
+}
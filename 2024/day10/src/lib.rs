@@ -1,11 +1,15 @@
+use common::{AocError, Solution};
 use std::collections::HashSet;
+use tracing::trace;
 
-fn main() {
-    let input = include_str!("input.txt");
-    println!("{:?}", solve(input));
-}
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
 
-struct Matrix {
+common::embedded_input!();
+
+pub struct Matrix {
     grid: Vec<Vec<u32>>,
     m: usize,
     n: usize,
@@ -35,8 +39,8 @@ impl Matrix {
         ) -> usize {
             visited.push((pos_i, pos_j));
             if height == 9 {
-                println!(
-                    "{:?}",
+                trace!(
+                    "trail {}",
                     visited
                         .iter()
                         .map(|(i, j)| format!(
@@ -82,36 +86,42 @@ impl Matrix {
     }
 }
 
-fn solve(input: &str) -> (usize, usize) {
-    let grid: Vec<Vec<u32>> = input
-        .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-        .collect();
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = Matrix;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let map = common::Matrix::parse(input, |c| c.to_digit(10))?;
+        Ok(Matrix {
+            grid: map.matrix,
+            m: map.height,
+            n: map.width,
+        })
+    }
+
+    fn part1(matrix: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(matrix.compute_score().0)
+    }
 
-    let (m, n) = (grid.len(), grid[0].len());
-    let matrix = Matrix { grid, m, n };
-    matrix.compute_score()
+    fn part2(matrix: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(matrix.compute_score().1)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
 
-    #[test]
-    fn test_part1() {
-        let input = indoc! {
-            r#"
-            89010123
-            78121874
-            87430965
-            96549874
-            45678903
-            32019012
-            01329801
-            10456732
-            "#
-        };
-        assert_eq!(solve(input), (36, 81));
+    common::example_tests! {
+        Day10;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day10;
+        max_size: 8;
     }
 }
@@ -0,0 +1,5 @@
+use y2024_day10::{Day10, INPUT};
+
+fn main() {
+    common::run::<Day10>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
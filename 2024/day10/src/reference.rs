@@ -0,0 +1,46 @@
+use common::differential::Expected;
+use std::collections::HashSet;
+
+/// Follows every trail from `(i, j)`, collecting the tops it ends at and
+/// returning how many trails there are.
+fn hike(
+    grid: &[Vec<u32>],
+    (i, j): (usize, usize),
+    tops: &mut HashSet<(usize, usize)>,
+) -> usize {
+    if grid[i][j] == 9 {
+        tops.insert((i, j));
+        return 1;
+    }
+    let mut trails = 0;
+    for (di, dj) in common::DIRECTIONS {
+        let (Some(ni), Some(nj)) =
+            (i.checked_add_signed(di), j.checked_add_signed(dj))
+        else {
+            continue;
+        };
+        if grid.get(ni).and_then(|row| row.get(nj)) == Some(&(grid[i][j] + 1))
+        {
+            trails += hike(grid, (ni, nj), tops);
+        }
+    }
+    trails
+}
+
+pub fn expected(input: &str) -> Expected {
+    let grid: Vec<Vec<u32>> = input
+        .lines()
+        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
+        .collect();
+    let (mut score, mut rating) = (0, 0);
+    for i in 0..grid.len() {
+        for j in 0..grid[0].len() {
+            if grid[i][j] == 0 {
+                let mut tops = HashSet::new();
+                rating += hike(&grid, (i, j), &mut tops);
+                score += tops.len();
+            }
+        }
+    }
+    Expected::both(score, rating)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day11::Day11);
+
+fn main() {
+    divan::main();
+}
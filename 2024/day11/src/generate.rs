@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// Up to `size` stones, mostly small numbers with the odd large one.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let stones: Vec<String> = (0..rng.gen_range(1..=size))
+        .map(|_| match rng.gen_range(0..4) {
+            0 => rng.gen_range(0..1_000_000),
+            _ => rng.gen_range(0..100),
+        })
+        .map(|stone: u64| stone.to_string())
+        .collect();
+    stones.join(" ") + "\n"
+}
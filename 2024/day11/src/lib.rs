@@ -0,0 +1,114 @@
+use common::parse::number;
+use common::{AocError, Solution};
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn apply_rule(n: usize) -> Result<Vec<usize>, AocError> {
+    Ok(match n {
+        0 => vec![1],
+        n if n.to_string().len() % 2 == 0 => {
+            let n_str = n.to_string();
+            let mid = n_str.len() / 2;
+
+            let (first_half, second_half) = n_str.split_at(mid);
+            vec![first_half.parse().unwrap(), second_half.parse().unwrap()]
+        }
+        _ => vec![n.checked_mul(2024).ok_or_else(|| {
+            AocError::new(format!("stone {n} times 2024 is too large"))
+        })?],
+    })
+}
+
+/// Stones that `stone` turns into after `blinks_left` more blinks,
+/// remembered in `seen` for this solve only.
+fn rec(
+    stone: usize,
+    blinks_left: usize,
+    seen: &mut HashMap<(usize, usize), usize>,
+) -> Result<usize, AocError> {
+    if blinks_left == 0 {
+        return Ok(1);
+    }
+    if let Some(&count) = seen.get(&(stone, blinks_left)) {
+        return Ok(count);
+    }
+    // Stones with many digits rarely repeat, so the memo alone does not
+    // bound the work
+    common::budget::check()?;
+
+    let count = apply_rule(stone)?
+        .into_iter()
+        .map(|stone| rec(stone, blinks_left - 1, seen))
+        .sum::<Result<usize, AocError>>()?;
+    seen.insert((stone, blinks_left), count);
+    Ok(count)
+}
+
+fn part1(stones: &[usize], n: usize) -> Result<usize, AocError> {
+    let mut blink = stones.to_vec();
+    for _i in 0..n {
+        // The row doubles every few blinks, so a single blink of a long
+        // row can already be over the budget
+        let mut next = Vec::with_capacity(blink.len() * 2);
+        for stone in blink {
+            common::budget::check()?;
+            next.extend(apply_rule(stone)?);
+        }
+        blink = next;
+    }
+    Ok(blink.len())
+}
+
+fn part2(stones: &[usize], n: usize) -> Result<usize, AocError> {
+    let mut seen = HashMap::new();
+    stones.iter().map(|&stone| rec(stone, n, &mut seen)).sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input.split_whitespace().map(number).collect()
+    }
+
+    fn part1(stones: &Self::Input<'_>) -> Result<usize, AocError> {
+        part1(stones, 25)
+    }
+
+    fn part2(stones: &Self::Input<'_>) -> Result<usize, AocError> {
+        part2(stones, 75)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_six_blinks() {
+        assert_eq!(part1(&Day11::parse("125 17").unwrap(), 6), Ok(22));
+        assert_eq!(part2(&Day11::parse("125 17").unwrap(), 6), Ok(22));
+    }
+
+    common::example_tests! {
+        Day11;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day11;
+        // Part 1 blinks every stone one at a time
+        seeds: 25;
+        max_size: 3;
+    }
+}
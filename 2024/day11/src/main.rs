@@ -0,0 +1,5 @@
+use y2024_day11::{Day11, INPUT};
+
+fn main() {
+    common::run::<Day11>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
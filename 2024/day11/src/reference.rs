@@ -0,0 +1,36 @@
+use common::differential::Expected;
+use std::collections::HashMap;
+
+/// Blinks with a count of the stones carrying each number, as stones with
+/// the same number always change the same way.
+fn blink(stones: &HashMap<u64, usize>) -> HashMap<u64, usize> {
+    let mut next = HashMap::new();
+    for (&stone, &count) in stones {
+        let digits = stone.to_string();
+        let changed = match stone {
+            0 => vec![1],
+            _ if digits.len() % 2 == 0 => {
+                let (left, right) = digits.split_at(digits.len() / 2);
+                vec![left.parse().unwrap(), right.parse().unwrap()]
+            }
+            _ => vec![stone * 2024],
+        };
+        for stone in changed {
+            *next.entry(stone).or_default() += count;
+        }
+    }
+    next
+}
+
+pub fn expected(input: &str) -> Expected {
+    let mut stones: HashMap<u64, usize> = HashMap::new();
+    for stone in input.split_whitespace() {
+        *stones.entry(stone.parse().unwrap()).or_default() += 1;
+    }
+    let mut counts = vec![];
+    for _ in 0..75 {
+        stones = blink(&stones);
+        counts.push(stones.values().sum::<usize>());
+    }
+    Expected::both(counts[24], counts[74])
+}
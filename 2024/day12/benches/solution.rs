@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day12::Day12);
+
+fn main() {
+    divan::main();
+}
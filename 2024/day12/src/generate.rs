@@ -0,0 +1,22 @@
+use rand::Rng;
+
+/// A garden of up to `size + 1` by `size + 1` plots of a few kinds of
+/// plants, where a plot often takes the plant of a neighbour so regions
+/// grow into odd shapes with holes.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let (m, n) = (rng.gen_range(1..=size + 1), rng.gen_range(1..=size + 1));
+    let kinds = rng.gen_range(1..=4);
+    let mut grid = vec![vec!['A'; n]; m];
+    for i in 0..m {
+        for j in 0..n {
+            grid[i][j] = match rng.gen_range(0..4) {
+                0 if i > 0 => grid[i - 1][j],
+                1 if j > 0 => grid[i][j - 1],
+                _ => char::from(b'A' + rng.gen_range(0..kinds)),
+            };
+        }
+    }
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
@@ -1,20 +1,24 @@
+use common::{AocError, Matrix, Solution};
 use std::collections::{HashMap, HashSet};
+use tracing::{debug, trace};
 
-fn main() {
-    let input = include_str!("input.txt");
-    println!("{:?}", solve(input));
-}
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+type Islands = HashMap<char, Vec<Vec<(usize, usize)>>>;
 
-fn find_islands(
-    grid: &[Vec<char>],
-) -> HashMap<char, Vec<Vec<(usize, usize)>>> {
+fn find_islands(grid: &[Vec<char>]) -> Islands {
     let mut visited = HashSet::new();
-    let mut islands: HashMap<char, Vec<Vec<(usize, usize)>>> = HashMap::new();
+    let mut islands: Islands = HashMap::new();
 
     let directions = [(-1, 0), (1, 0), (0, -1), (0, 1)];
 
     let rows = grid.len();
-    let cols = grid[0].len();
+    let cols = grid.first().map_or(0, Vec::len);
 
     for i in 0..rows {
         for j in 0..cols {
@@ -101,80 +105,82 @@ fn count_corners(i: isize, j: isize, island: &[(usize, usize)]) -> usize {
         .count()
 }
 
-fn solve(input: &str) -> (usize, usize) {
-    let grid: Vec<Vec<char>> =
-        input.lines().map(|line| line.chars().collect()).collect();
-    let (m, n) = (grid.len(), grid[0].len());
-    let islands = find_islands(&grid);
-    println!("{:?}", islands);
-
-    let part1 = islands
-        .values()
-        .map(|islands| {
-            islands
-                .iter()
-                .map(move |island| {
-                    let perimeter = calculate_perimeter(island, m, n);
-                    // println!("{ch}: {} * {:?}", island.len(), perimeter);
-                    perimeter * island.len()
-                })
-                .sum::<usize>()
-        })
-        .sum();
+pub struct Garden {
+    islands: Islands,
+    m: usize,
+    n: usize,
+}
 
-    let part2 = islands
-        .iter()
-        .map(|(ch, islands)| {
-            islands
-                .iter()
-                .map(|island| {
-                    let corners = island
-                        .iter()
-                        .map(|(i, j)| {
-                            count_corners(*i as isize, *j as isize, island)
-                        })
-                        .sum::<usize>();
-                    println!("{ch}: {} * {:?}", island.len(), corners);
-                    corners * island.len()
-                })
-                .sum::<usize>()
-        })
-        .sum();
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let grid =
+            Matrix::parse(input, |c| c.is_ascii_alphabetic().then_some(c))?;
+        let (m, n) = (grid.height, grid.width);
+        let islands = find_islands(&grid.matrix);
+        debug!("{} kinds of plants", islands.len());
+        trace!("{islands:?}");
+        Ok(Garden { islands, m, n })
+    }
+
+    fn part1(garden: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(garden
+            .islands
+            .iter()
+            .map(|(ch, islands)| {
+                islands
+                    .iter()
+                    .map(move |island| {
+                        let perimeter =
+                            calculate_perimeter(island, garden.m, garden.n);
+                        trace!("{ch}: {} * {perimeter}", island.len());
+                        perimeter * island.len()
+                    })
+                    .sum::<usize>()
+            })
+            .sum())
+    }
 
-    (part1, part2)
+    fn part2(garden: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(garden
+            .islands
+            .iter()
+            .map(|(ch, islands)| {
+                islands
+                    .iter()
+                    .map(|island| {
+                        let corners = island
+                            .iter()
+                            .map(|(i, j)| {
+                                count_corners(*i as isize, *j as isize, island)
+                            })
+                            .sum::<usize>();
+                        trace!("{ch}: {} * {corners}", island.len());
+                        corners * island.len()
+                    })
+                    .sum::<usize>()
+            })
+            .sum())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use indoc::indoc;
-
-    #[test]
-    fn test_part1() {
-        let input = indoc! {
-            r#"
-            AAAA
-            BBCD
-            BBCC
-            EEEC
-            "#
-        };
-        assert_eq!(solve(input), (140, 80));
-
-        let input = indoc! {
-            r#"
-            RRRRIICCFF
-            RRRRIICCCF
-            VVRRRCCFFF
-            VVRCCCJFFF
-            VVVVCJJCFE
-            VVIVCCJJEE
-            VVIIICJJEE
-            MIIIIIJJEE
-            MIIISIJEEE
-            MMMISSJEEE
-            "#
-        };
-        assert_eq!(solve(input).0, 1930);
+
+    common::example_tests! {
+        Day12;
+        example: part1, part2;
+        larger: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day12;
+        max_size: 8;
     }
 }
@@ -0,0 +1,5 @@
+use y2024_day12::{Day12, INPUT};
+
+fn main() {
+    common::run::<Day12>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,66 @@
+use common::differential::Expected;
+
+/// Labels each region by flood fill, then prices it by its fences. A fence
+/// starts a new side unless the plot to its left along the side has the
+/// same fence.
+pub fn expected(input: &str) -> Expected {
+    let grid: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let (m, n) = (grid.len() as isize, grid[0].len() as isize);
+    let mut region = vec![vec![usize::MAX; n as usize]; m as usize];
+    let mut regions = 0;
+    for i in 0..m as usize {
+        for j in 0..n as usize {
+            if region[i][j] != usize::MAX {
+                continue;
+            }
+            let mut stack = vec![(i, j)];
+            while let Some((x, y)) = stack.pop() {
+                if region[x][y] != usize::MAX {
+                    continue;
+                }
+                region[x][y] = regions;
+                for (dx, dy) in common::DIRECTIONS {
+                    let (nx, ny) = (x as isize + dx, y as isize + dy);
+                    if (0..m).contains(&nx)
+                        && (0..n).contains(&ny)
+                        && grid[nx as usize][ny as usize] == grid[i][j]
+                    {
+                        stack.push((nx as usize, ny as usize));
+                    }
+                }
+            }
+            regions += 1;
+        }
+    }
+
+    let id = |i: isize, j: isize| -> Option<usize> {
+        ((0..m).contains(&i) && (0..n).contains(&j))
+            .then(|| region[i as usize][j as usize])
+    };
+    let fenced = |i: isize, j: isize, (di, dj): (isize, isize)| {
+        id(i, j).is_some() && id(i, j) != id(i + di, j + dj)
+    };
+    let (mut area, mut perimeter, mut sides) =
+        (vec![0; regions], vec![0; regions], vec![0; regions]);
+    for i in 0..m {
+        for j in 0..n {
+            let r = id(i, j).unwrap();
+            area[r] += 1;
+            for (di, dj) in common::DIRECTIONS {
+                if !fenced(i, j, (di, dj)) {
+                    continue;
+                }
+                perimeter[r] += 1;
+                let (li, lj) = (i + dj, j - di);
+                if id(li, lj) != Some(r) || !fenced(li, lj, (di, dj)) {
+                    sides[r] += 1;
+                }
+            }
+        }
+    }
+    let price = |fences: &[usize]| -> usize {
+        (0..regions).map(|r| area[r] * fences[r]).sum()
+    };
+    Expected::both(price(&perimeter), price(&sides))
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day13::Day13);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,44 @@
+use rand::Rng;
+
+/// `size` claw machines. Most prizes are some number of presses of each
+/// button away, now and then more than the 100 that part 1 allows; the
+/// rest are anywhere. Puzzle inputs never have both buttons move the claw
+/// in the same direction, but now and then these do, so that a prize can
+/// be won in more than one way.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let machines: Vec<String> = (0..size)
+        .map(|_| {
+            let (a, b) = if rng.gen_bool(0.2) {
+                let step: (i64, i64) =
+                    (rng.gen_range(1..=7), rng.gen_range(1..=7));
+                let (m, n) = (rng.gen_range(1..=3), rng.gen_range(1..=3));
+                ((step.0 * m, step.1 * m), (step.0 * n, step.1 * n))
+            } else {
+                loop {
+                    let a: (i64, i64) =
+                        (rng.gen_range(1..=20), rng.gen_range(1..=20));
+                    let b: (i64, i64) =
+                        (rng.gen_range(1..=20), rng.gen_range(1..=20));
+                    if a.0 * b.1 != a.1 * b.0 {
+                        break (a, b);
+                    }
+                }
+            };
+            let prize = match rng.gen_bool(0.7) {
+                true => {
+                    let (x, y) =
+                        (rng.gen_range(0..=120), rng.gen_range(0..=120));
+                    (a.0 * x + b.0 * y, a.1 * x + b.1 * y)
+                }
+                false => (rng.gen_range(0..=2000), rng.gen_range(0..=2000)),
+            };
+            format!(
+                "Button A: X+{}, Y+{}\n\
+                 Button B: X+{}, Y+{}\n\
+                 Prize: X={}, Y={}\n",
+                a.0, a.1, b.0, b.1, prize.0, prize.1
+            )
+        })
+        .collect();
+    machines.join("\n")
+}
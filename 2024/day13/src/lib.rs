@@ -0,0 +1,251 @@
+use common::parse::finish;
+use common::{AocError, Solution};
+use nom::character::complete::u32 as nom_u32;
+use nom::combinator::map;
+use nom::multi::separated_list1;
+use nom::sequence::separated_pair;
+use nom::{
+    bytes::complete::tag,
+    character::complete::newline,
+    sequence::{preceded, terminated},
+    IResult,
+};
+use tracing::trace;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+#[derive(Debug)]
+pub struct ButtonPrize {
+    button_a: (i64, i64),
+    button_b: (i64, i64),
+    prize: (i64, i64),
+}
+
+// Buttons and prizes are far below u32, which keeps every product in
+// `solve_linear_system` well inside i128
+fn coordinate(input: &str) -> IResult<&str, i64> {
+    map(nom_u32, i64::from)(input)
+}
+
+fn parse_button(input: &str) -> IResult<&str, (i64, i64)> {
+    separated_pair(
+        preceded(tag("X+"), coordinate),
+        tag(", "),
+        preceded(tag("Y+"), coordinate),
+    )(input)
+}
+
+fn parse_prize(input: &str) -> IResult<&str, (i64, i64)> {
+    separated_pair(
+        preceded(tag("X="), coordinate),
+        tag(", "),
+        preceded(tag("Y="), coordinate),
+    )(input)
+}
+
+// Parse each button-prize block (Button A, Button B, Prize) and terminated by newline
+fn parse_button_prize(input: &str) -> IResult<&str, ButtonPrize> {
+    let (input, button_a) =
+        terminated(preceded(tag("Button A: "), parse_button), newline)(input)?;
+    let (input, button_b) =
+        terminated(preceded(tag("Button B: "), parse_button), newline)(input)?;
+    let (input, prize) = preceded(tag("Prize: "), parse_prize)(input)?;
+
+    Ok((
+        input,
+        ButtonPrize {
+            button_a,
+            button_b,
+            prize,
+        },
+    ))
+}
+
+/// `g` with `a * x + b * y == g`, the greatest common divisor of `a` and `b`,
+/// as `(g, x, y)`.
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        return (a, 1, 0);
+    }
+    let (g, x, y) = extended_gcd(b, a % b);
+    (g, y, x - (a / b) * y)
+}
+
+/// The cheapest presses `(x, y)`, at 3 tokens for each `x` and 1 for each
+/// `y`, with `a * x + b * y == c`, where `a` and `b` are not both 0.
+fn cheapest_on_line(
+    a: i128,
+    b: i128,
+    c: i128,
+    max_presses: Option<i128>,
+) -> Option<(i128, i128)> {
+    // No button that moves the claw can be pressed more than `c` times
+    let max = max_presses.unwrap_or(c);
+    match (a, b) {
+        (0, b) => (c % b == 0 && c / b <= max).then_some((0, c / b)),
+        (a, 0) => (c % a == 0 && c / a <= max).then_some((c / a, 0)),
+        (a, b) => {
+            let (g, x, y) = extended_gcd(a, b);
+            if c % g != 0 {
+                return None;
+            }
+            let (x, y) = (x * (c / g), y * (c / g));
+            // Every way is x + k * step_x presses of A and y - k * step_y of
+            // B for some k, each step costing the same, so the cheapest is
+            // at the lowest or the highest k in 0..=max presses of both
+            let (step_x, step_y) = (b / g, a / g);
+            let low =
+                (-(x.div_euclid(step_x))).max(-((max - y).div_euclid(step_y)));
+            let high = (max - x).div_euclid(step_x).min(y.div_euclid(step_y));
+            if low > high {
+                return None;
+            }
+            let k = if 3 * step_x > step_y { low } else { high };
+            Some((x + k * step_x, y - k * step_y))
+        }
+    }
+}
+
+/// The cheapest presses of A and B that move the claw by `(c1, c2)`, each
+/// pressed at most `max_presses` times if given.
+fn solve_linear_system(
+    a1: i64,
+    a2: i64,
+    b1: i64,
+    b2: i64,
+    c1: i64,
+    c2: i64,
+    max_presses: Option<i128>,
+) -> Option<(i128, i128)> {
+    let [a1, a2, b1, b2, c1, c2] = [a1, a2, b1, b2, c1, c2].map(i128::from);
+    let denominator = a1 * b2 - a2 * b1;
+    if denominator == 0 {
+        // The buttons move the claw along one line, which the prize has to
+        // be on, and then many ways may reach it
+        if a1 * c2 != a2 * c1 || b1 * c2 != b2 * c1 {
+            return None;
+        }
+        return match (a1, b1, a2, b2) {
+            (0, 0, 0, 0) => (c1 == 0 && c2 == 0).then_some((0, 0)),
+            (0, 0, a2, b2) => cheapest_on_line(a2, b2, c2, max_presses),
+            (a1, b1, _, _) => cheapest_on_line(a1, b1, c1, max_presses),
+        };
+    }
+    let numerator_x = b2 * c1 - b1 * c2;
+    let numerator_y = a1 * c2 - a2 * c1;
+
+    if numerator_x % denominator != 0 || numerator_y % denominator != 0 {
+        return None;
+    }
+    let (x, y) = (numerator_x / denominator, numerator_y / denominator);
+    // A button cannot be pressed a negative number of times
+    let max = max_presses.unwrap_or(i128::MAX);
+    (x >= 0 && y >= 0 && x <= max && y <= max).then_some((x, y))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<ButtonPrize>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        finish(separated_list1(tag("\n\n"), parse_button_prize)(input))
+    }
+
+    fn part1(button_prizes: &Self::Input<'_>) -> Result<i64, AocError> {
+        button_prizes
+            .iter()
+            .filter_map(|button_prize| {
+                solve_linear_system(
+                    button_prize.button_a.0,
+                    button_prize.button_a.1,
+                    button_prize.button_b.0,
+                    button_prize.button_b.1,
+                    button_prize.prize.0,
+                    button_prize.prize.1,
+                    Some(100),
+                )
+            })
+            .map(|(a, b)| {
+                trace!("{a} presses of A, {b} of B");
+                a * 3 + b
+            })
+            .sum::<i128>()
+            .try_into()
+            .map_err(|_| AocError::new("the tokens add up past i64"))
+    }
+
+    fn part2(button_prizes: &Self::Input<'_>) -> Result<i64, AocError> {
+        button_prizes
+            .iter()
+            .filter_map(|button_prize| {
+                solve_linear_system(
+                    button_prize.button_a.0,
+                    button_prize.button_a.1,
+                    button_prize.button_b.0,
+                    button_prize.button_b.1,
+                    button_prize.prize.0 + 10000000000000,
+                    button_prize.prize.1 + 10000000000000,
+                    None,
+                )
+            })
+            .map(|(a, b)| {
+                trace!("{a} presses of A, {b} of B");
+                a * 3 + b
+            })
+            .sum::<i128>()
+            .try_into()
+            .map_err(|_| AocError::new("the tokens add up past i64"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day13;
+        example: part1, part2;
+    }
+
+    #[test]
+    fn test_collinear_buttons() {
+        // Ten presses of B are cheaper than five of A
+        assert_eq!(
+            solve_linear_system(2, 2, 1, 1, 10, 10, None),
+            Some((0, 10))
+        );
+        // Presses of A are cheaper, but only as many as fit
+        assert_eq!(
+            solve_linear_system(4, 4, 1, 1, 10, 10, None),
+            Some((2, 2))
+        );
+        assert_eq!(
+            solve_linear_system(4, 6, 6, 9, 10, 15, Some(100)),
+            Some((1, 1))
+        );
+        assert_eq!(solve_linear_system(4, 6, 6, 9, 10, 16, None), None);
+        let far = 10000000000000;
+        assert_eq!(
+            solve_linear_system(4, 4, 1, 1, far + 1, far + 1, None),
+            Some((i128::from(far / 4), 1))
+        );
+        // B alone is cheapest, but needs more than 100 presses
+        assert_eq!(
+            solve_linear_system(2, 2, 1, 1, 200, 200, Some(100)),
+            Some((50, 100))
+        );
+    }
+
+    common::generator_tests! {
+        Day13;
+        max_size: 5;
+    }
+}
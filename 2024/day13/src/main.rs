@@ -0,0 +1,5 @@
+use y2024_day13::{Day13, INPUT};
+
+fn main() {
+    common::run::<Day13>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
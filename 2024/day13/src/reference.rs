@@ -0,0 +1,34 @@
+use common::differential::Expected;
+
+fn numbers(line: &str) -> (i64, i64) {
+    let digits: Vec<i64> = line
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().unwrap())
+        .collect();
+    (digits[0], digits[1])
+}
+
+/// Tries up to 100 presses of each button on every machine. Part 2 puts
+/// the prizes too far away for that and is not checked.
+pub fn expected(input: &str) -> Expected {
+    let lines: Vec<&str> = input.lines().filter(|l| !l.is_empty()).collect();
+    let tokens: i64 = lines
+        .chunks(3)
+        .filter_map(|machine| {
+            let (a, b, prize) = (
+                numbers(machine[0]),
+                numbers(machine[1]),
+                numbers(machine[2]),
+            );
+            (0..=100)
+                .flat_map(|i| (0..=100).map(move |j| (i, j)))
+                .filter(|(i, j)| {
+                    (a.0 * i + b.0 * j, a.1 * i + b.1 * j) == prize
+                })
+                .map(|(i, j)| 3 * i + j)
+                .min()
+        })
+        .sum();
+    Expected::part1(tokens)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day14::Day14);
+
+fn main() {
+    divan::main();
+}
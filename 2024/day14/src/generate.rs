@@ -0,0 +1,15 @@
+use rand::Rng;
+
+/// `size` robots on the 101 by 103 tiles of the puzzle, starting bunched up
+/// in one corner so that some share a tile and the picture, the first
+/// second they do not, is a while away.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let (x, y) = (rng.gen_range(0..4), rng.gen_range(0..4));
+            let (vx, vy) =
+                (rng.gen_range(-100..=100), rng.gen_range(-100..=100));
+            format!("p={x},{y} v={vx},{vy}\n")
+        })
+        .collect()
+}
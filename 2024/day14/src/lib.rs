@@ -0,0 +1,207 @@
+use common::parse::finish;
+use common::viz::{Cell, Frame, Recording, Visualize, GREEN};
+use common::{AocError, Solution};
+use nom::bytes::complete::tag;
+use nom::character::complete::{i64 as nom_i64, space1, u64 as nom_u64};
+use nom::sequence::{preceded, separated_pair};
+use nom::IResult;
+use std::collections::HashSet;
+use tracing::trace;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+#[derive(Debug, Default, Copy, Clone)]
+pub struct Robot {
+    pos: (u64, u64),
+    velocity: (i64, i64),
+}
+
+impl Robot {
+    fn position_after(
+        &self,
+        seconds: i64,
+        m: usize,
+        n: usize,
+    ) -> (usize, usize) {
+        // Wrapping the velocity first keeps the product small, however
+        // fast the robot is
+        let wrap = |pos: u64, velocity: i64, len: usize| {
+            let pos = (pos % len as u64) as i64;
+            let len = len as i64;
+            let step = velocity.rem_euclid(len) * seconds.rem_euclid(len);
+            (pos + step).rem_euclid(len) as usize
+        };
+        (
+            wrap(self.pos.0, self.velocity.0, m),
+            wrap(self.pos.1, self.velocity.1, n),
+        )
+    }
+}
+
+fn parse_coord(input: &str) -> IResult<&str, (u64, u64)> {
+    separated_pair(nom_u64, tag(","), nom_u64)(input)
+}
+
+fn parse_velocity(input: &str) -> IResult<&str, (i64, i64)> {
+    separated_pair(nom_i64, tag(","), nom_i64)(input)
+}
+
+fn parse_robot(input: &str) -> IResult<&str, Robot> {
+    let (input, ((j, i), (vj, vi))) = separated_pair(
+        preceded(tag("p="), parse_coord),
+        space1,
+        preceded(tag("v="), parse_velocity),
+    )(input)?;
+    Ok((
+        input,
+        Robot {
+            pos: (i, j),
+            velocity: (vi, vj),
+        },
+    ))
+}
+
+fn part1(robots: &[Robot], m: usize, n: usize) -> usize {
+    let mut matrix: Vec<Vec<usize>> =
+        (0..m).map(|_i| (0..n).map(|_j| 0).collect()).collect();
+
+    robots.iter().for_each(|robot| {
+        let (res_i, res_j) = robot.position_after(100, m, n);
+        matrix[res_i][res_j] += 1;
+    });
+
+    trace!(
+        "after 100 seconds:\n{}",
+        matrix
+            .iter()
+            .map(|row| row.iter().map(|n| n.to_string()).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    );
+    [
+        (0..m / 2, n / 2 + 1..n),
+        (0..m / 2, 0..n / 2),
+        (m / 2 + 1..m, 0..n / 2),
+        (m / 2 + 1..m, n / 2 + 1..n),
+    ]
+    .into_iter()
+    .map(|(rows, cols)| {
+        rows.map(|i| cols.clone().map(|j| matrix[i][j]).sum::<usize>())
+            .sum::<usize>()
+    })
+    .product()
+}
+
+// The picture shows up the first time no two robots share a tile; the
+// positions repeat after m * n seconds, so there is nothing to find past that.
+fn part2(robots: &[Robot], m: usize, n: usize) -> Result<usize, AocError> {
+    for i in common::progress::bar(m * n, "day 14 seconds").wrap_iter(0..m * n)
+    {
+        common::budget::check()?;
+        let mut seen = HashSet::new();
+        if robots
+            .iter()
+            .all(|robot| seen.insert(robot.position_after(i as i64, m, n)))
+        {
+            return Ok(i);
+        }
+    }
+    Err(AocError::new("the robots never line up into a picture"))
+}
+
+fn robots_frame(
+    robots: &[Robot],
+    seconds: usize,
+    m: usize,
+    n: usize,
+) -> Frame {
+    let tiles: HashSet<(usize, usize)> = robots
+        .iter()
+        .map(|robot| robot.position_after(seconds as i64, m, n))
+        .collect();
+    Frame::from_fn(m, n, |i, j| match tiles.contains(&(i, j)) {
+        true => Cell::colored('#', GREEN),
+        false => Cell::from('.'),
+    })
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = Vec<Robot>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input
+            .lines()
+            .map(|line| finish(parse_robot(line)))
+            .collect()
+    }
+
+    fn part1(robots: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(part1(robots, 103, 101))
+    }
+
+    fn part2(robots: &Self::Input<'_>) -> Result<usize, AocError> {
+        part2(robots, 103, 101)
+    }
+}
+
+impl Visualize for Day14 {
+    /// The robots from the start until they draw the picture.
+    fn visualize(
+        robots: &Self::Input<'_>,
+        recording: &mut Recording,
+    ) -> Result<(), AocError> {
+        let (m, n) = (103, 101);
+        let picture = part2(robots, m, n)?;
+        for seconds in 0..picture {
+            recording.record(|| robots_frame(robots, seconds, m, n));
+        }
+        recording.finish(robots_frame(robots, picture, m, n));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_part1() {
+        let input = indoc! {
+            r#"
+            p=0,4 v=3,-3
+            p=6,3 v=-1,-3
+            p=10,3 v=-1,2
+            p=2,0 v=2,-1
+            p=0,0 v=1,3
+            p=3,0 v=-2,-2
+            p=7,6 v=-1,-3
+            p=3,0 v=-1,-2
+            p=9,3 v=2,3
+            p=7,3 v=-1,2
+            p=2,4 v=2,-3
+            p=9,5 v=-3,-3
+            "#
+        };
+        assert_eq!(part1(&Day14::parse(input).unwrap(), 7, 11), 12);
+    }
+
+    #[test]
+    fn test_part2() {
+        // assert_eq!();
+    }
+
+    common::generator_tests! {
+        Day14;
+        max_size: 8;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day14::{Day14, INPUT};
+
+fn main() {
+    common::run::<Day14>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
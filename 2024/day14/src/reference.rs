@@ -0,0 +1,55 @@
+use common::differential::Expected;
+use std::collections::HashSet;
+
+const WIDTH: i64 = 101;
+const HEIGHT: i64 = 103;
+
+/// Moves the robots one second at a time: 100 seconds for the safety
+/// factor, and on until no two robots share a tile for the picture, which
+/// has no answer if the positions come round again first.
+pub fn expected(input: &str) -> Expected {
+    let mut robots: Vec<[i64; 4]> = input
+        .lines()
+        .map(|line| {
+            let numbers: Vec<i64> = line
+                .split(|c: char| c != '-' && !c.is_ascii_digit())
+                .filter(|s| !s.is_empty())
+                .map(|s| s.parse().unwrap())
+                .collect();
+            [numbers[0], numbers[1], numbers[2], numbers[3]]
+        })
+        .collect();
+    let spread = |robots: &[[i64; 4]]| {
+        let mut seen = HashSet::new();
+        robots.iter().all(|&[x, y, _, _]| seen.insert((x, y)))
+    };
+
+    let (mut safety, mut picture) = (None, None);
+    for second in 0..WIDTH * HEIGHT {
+        if picture.is_none() && spread(&robots) {
+            picture = Some(second);
+        }
+        if second == 100 {
+            let mut quadrants = [0; 4];
+            for &[x, y, _, _] in &robots {
+                if x != WIDTH / 2 && y != HEIGHT / 2 {
+                    let quadrant = (x < WIDTH / 2) as usize * 2
+                        + (y < HEIGHT / 2) as usize;
+                    quadrants[quadrant] += 1;
+                }
+            }
+            safety = Some(quadrants.iter().product::<usize>());
+        }
+        if picture.is_some() && safety.is_some() {
+            break;
+        }
+        for [x, y, vx, vy] in &mut robots {
+            *x = (*x + *vx).rem_euclid(WIDTH);
+            *y = (*y + *vy).rem_euclid(HEIGHT);
+        }
+    }
+    Expected {
+        part1: safety.map(|safety| safety.to_string()),
+        part2: picture.map(|picture| picture.to_string()),
+    }
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day15::Day15);
+
+fn main() {
+    divan::main();
+}
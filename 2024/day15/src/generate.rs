@@ -0,0 +1,33 @@
+use rand::Rng;
+
+/// A walled warehouse of up to `size + 4` by `size + 4` tiles with boxes
+/// and the odd wall inside, and the robot's moves in lines of 20.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let (m, n) = (rng.gen_range(3..=size + 4), rng.gen_range(3..=size + 4));
+    let mut grid: Vec<Vec<char>> = (0..m)
+        .map(|i| {
+            (0..n)
+                .map(|j| match rng.gen_range(0..10) {
+                    _ if i == 0 || j == 0 || i == m - 1 || j == n - 1 => '#',
+                    0 => '#',
+                    1..=3 => 'O',
+                    _ => '.',
+                })
+                .collect()
+        })
+        .collect();
+    grid[rng.gen_range(1..m - 1)][rng.gen_range(1..n - 1)] = '@';
+
+    let moves: Vec<char> = (0..rng.gen_range(1..=size * 10))
+        .map(|_| ['<', '>', '^', 'v'][rng.gen_range(0..4)])
+        .collect();
+    let grid: String = grid
+        .iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect();
+    let moves: String = moves
+        .chunks(20)
+        .map(|line| line.iter().collect::<String>() + "\n")
+        .collect();
+    grid + "\n" + &moves
+}
@@ -1,13 +1,34 @@
-use itertools::Itertools;
+use common::parse::split_once;
+use common::stepper::{Snapshot, StepThrough, Tape};
+use common::viz::{Cell, Frame, Recording, Visualize, RED, YELLOW};
+use common::{AocError, Matrix, Solution};
 use std::collections::HashSet;
+use tracing::{debug, trace};
 
-fn main() {
-    let input = include_str!("input.txt");
-    println!("{:?}", part1(input));
-    println!("{:?}", part2(input));
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn render(matrix: &[Vec<char>]) -> String {
+    matrix
+        .iter()
+        .map(|row| row.iter().collect::<String>())
+        .collect::<Vec<_>>()
+        .join("\n")
 }
 
-#[derive(Debug, PartialEq)]
+fn warehouse_frame(matrix: &[Vec<char>]) -> Frame {
+    Frame::from_rows(matrix, |&c| match c {
+        '@' => Cell::colored(c, RED),
+        'O' | '[' | ']' => Cell::colored(c, YELLOW),
+        _ => Cell::from(c),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq)]
 enum Direction {
     Up,
     Down,
@@ -26,7 +47,11 @@ impl Direction {
     }
 }
 
-struct Game {
+/// A move and where the robot stands after it.
+type Move<'a> = (&'a Direction, (isize, isize));
+
+#[derive(Clone)]
+pub struct Game {
     matrix: Vec<Vec<char>>,
     m: isize,
     n: isize,
@@ -35,39 +60,54 @@ struct Game {
 }
 
 impl Game {
-    fn new(input: &str) -> Self {
-        let (matrix_str, directions_str): (&str, &str) =
-            input.split("\n\n").collect_tuple().unwrap();
-        let matrix: Vec<Vec<char>> = matrix_str
-            .lines()
-            .map(|line| line.chars().collect())
-            .collect();
-        let directions: Vec<Direction> = directions_str
-            .lines()
-            .flat_map(|line| line.chars())
-            .map(|c| match c {
-                '>' => Direction::Right,
-                '<' => Direction::Left,
-                '^' => Direction::Up,
-                'v' => Direction::Down,
-                _ => panic!("disco!"),
+    fn new(input: &str) -> Result<Self, AocError> {
+        let (matrix_str, directions_str) = split_once(input, "\n\n")?;
+        let warehouse =
+            Matrix::parse(matrix_str, |c| "#.O@".contains(c).then_some(c))?;
+        let directions = directions_str
+            .char_indices()
+            .filter(|(_, c)| *c != '\n')
+            .map(|(i, c)| match c {
+                '>' => Ok(Direction::Right),
+                '<' => Ok(Direction::Left),
+                '^' => Ok(Direction::Up),
+                'v' => Ok(Direction::Down),
+                _ => Err(AocError::at(
+                    &directions_str[i..],
+                    format!("unexpected {c:?}"),
+                )),
             })
-            .collect();
-        let (m, n) = (matrix.len(), matrix[0].len());
-        let (mut pos_i, mut pos_j) = (0, 0);
-        for (i, j) in (0..m).flat_map(|i| (0..n).map(move |j| (i, j))) {
-            if matrix[i][j] == '@' {
-                (pos_i, pos_j) = (i as isize, j as isize);
-            }
+            .collect::<Result<Vec<Direction>, _>>()?;
+        let robots = warehouse.matrix.iter().flatten().filter(|&&c| c == '@');
+        if robots.count() != 1 {
+            return Err(AocError::new(
+                "expected one robot @ in the warehouse",
+            ));
+        }
+        // The moves only look for walls, not for the edge of the map
+        let (height, width) = (warehouse.height, warehouse.width);
+        let on_edge = |i: usize, j: usize| {
+            i == 0 || j == 0 || i == height - 1 || j == width - 1
+        };
+        let walled = warehouse.matrix.iter().enumerate().all(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .all(|(j, &c)| c == '#' || !on_edge(i, j))
+        });
+        if !walled {
+            return Err(AocError::new(
+                "expected walls all around the warehouse",
+            ));
         }
+        let (pos_i, pos_j) = warehouse.find(&'@').expect("there is a robot");
 
-        Game {
-            matrix,
-            m: m as isize,
-            n: n as isize,
+        Ok(Game {
+            matrix: warehouse.matrix,
+            m: warehouse.height as isize,
+            n: warehouse.width as isize,
             directions,
-            start: (pos_i, pos_j),
-        }
+            start: (pos_i as isize, pos_j as isize),
+        })
     }
 
     fn get_next_empty(
@@ -103,11 +143,13 @@ impl Game {
         None
     }
 
-    fn run(&mut self) -> isize {
+    /// Moves the robot, calling `on_move` with each move, the robot's
+    /// position after it and the warehouse.
+    fn run(&mut self, mut on_move: impl FnMut(&Move, &[Vec<char>])) -> isize {
         let (mut pos_i, mut pos_j) = self.start;
         for dir in self.directions.iter() {
             let (di, dj) = dir.get_direction();
-            // println!("{:?}", dir);
+            trace!("move {dir:?}");
 
             if pos_i + di >= 0
                 && pos_i + di < self.m
@@ -116,7 +158,7 @@ impl Game {
             {
                 let (next_i, next_j) = (pos_i + di, pos_j + dj);
                 match self.matrix[next_i as usize][next_j as usize] {
-                    '#' => continue,
+                    '#' => {}
                     '.' => {
                         self.matrix[pos_i as usize][pos_j as usize] = '.';
                         self.matrix[next_i as usize][next_j as usize] = '@';
@@ -134,13 +176,11 @@ impl Game {
                             (pos_i, pos_j) = (next_i, next_j);
                         }
                     }
-                    _ => panic!("disco!"),
+                    _ => unreachable!("the robot is the only @"),
                 }
             }
-            // for v in self.matrix.iter() {
-            //     println!("{}", v.iter().map(|n| n.to_string()).collect::<String>());
-            // }
-            // println!();
+            trace!("warehouse:\n{}", render(&self.matrix));
+            on_move(&(dir, (pos_i, pos_j)), &self.matrix);
         }
 
         (0..self.m)
@@ -163,18 +203,13 @@ impl Game {
                         'O' => vec!['[', ']'],
                         '.' => vec!['.', '.'],
                         '@' => vec!['@', '.'],
-                        _ => panic!("disco!"),
+                        _ => unreachable!("parse only lets # . O @ through"),
                     })
                     .collect()
             })
             .collect();
 
-        for v in matrix.iter() {
-            println!(
-                "{}",
-                v.iter().map(|n| n.to_string()).collect::<String>()
-            );
-        }
+        debug!("wide warehouse:\n{}", render(&matrix));
 
         self.m = matrix.len() as isize;
         self.n = matrix[0].len() as isize;
@@ -230,10 +265,11 @@ impl Game {
         Some(finals)
     }
 
-    fn run2(&mut self) -> isize {
+    /// Like `run`, on the wide warehouse of part 2.
+    fn run2(&mut self, mut on_move: impl FnMut(&Move, &[Vec<char>])) -> isize {
         let (mut pos_i, mut pos_j) = self.start;
-        for (_i, dir) in self.directions.iter().enumerate() {
-            // println!("step {i}: {:?}", dir);
+        for dir in self.directions.iter() {
+            trace!("move {dir:?}");
 
             let (di, dj) = dir.get_direction();
             let (next_i, next_j) = (pos_i + di, pos_j + dj);
@@ -241,7 +277,7 @@ impl Game {
                 continue;
             }
             match self.matrix[next_i as usize][next_j as usize] {
-                '#' => continue,
+                '#' => {}
                 '.' => {
                     self.matrix[pos_i as usize][pos_j as usize] = '.';
                     self.matrix[next_i as usize][next_j as usize] = '@';
@@ -309,20 +345,12 @@ impl Game {
                         }
                     }
                 },
-                _ => panic!("disco!"),
+                _ => unreachable!("the robot is the only @"),
             }
-            // for v in self.matrix.iter() {
-            //     println!("{}", v.iter().map(|n| n.to_string()).collect::<String>());
-            // }
-            // println!();
-        }
-        for v in self.matrix.iter() {
-            println!(
-                "{}",
-                v.iter().map(|n| n.to_string()).collect::<String>()
-            );
+            trace!("warehouse:\n{}", render(&self.matrix));
+            on_move(&(dir, (pos_i, pos_j)), &self.matrix);
         }
-        println!();
+        debug!("final warehouse:\n{}", render(&self.matrix));
 
         (0..self.m)
             .flat_map(|i| (0..self.n).map(move |j| (i, j)))
@@ -336,15 +364,75 @@ impl Game {
     }
 }
 
-fn part1(input: &str) -> isize {
-    let mut game = Game::new(input);
-    game.run()
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Game;
+    type Part1 = isize;
+    type Part2 = isize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Game::new(input)
+    }
+
+    fn part1(game: &Self::Input<'_>) -> Result<isize, AocError> {
+        let mut game = game.clone();
+        Ok(game.run(|_, _| {}))
+    }
+
+    fn part2(game: &Self::Input<'_>) -> Result<isize, AocError> {
+        let mut game = game.clone();
+        game.expand_matrix();
+        Ok(game.run2(|_, _| {}))
+    }
 }
 
-fn part2(input: &str) -> isize {
-    let mut game = Game::new(input);
-    game.expand_matrix();
-    game.run2()
+impl Visualize for Day15 {
+    /// The robot pushing the wide boxes of part 2 around.
+    fn visualize(
+        game: &Self::Input<'_>,
+        recording: &mut Recording,
+    ) -> Result<(), AocError> {
+        let mut game = game.clone();
+        game.expand_matrix();
+        recording.record(|| warehouse_frame(&game.matrix));
+        game.run2(|_, matrix| recording.record(|| warehouse_frame(matrix)));
+        recording.finish(warehouse_frame(&game.matrix));
+        Ok(())
+    }
+}
+
+impl StepThrough for Day15 {
+    fn step_through(
+        game: &Self::Input<'_>,
+        part: u8,
+        tape: &mut Tape,
+    ) -> Result<(), AocError> {
+        let mut game = game.clone();
+        if part == 2 {
+            game.expand_matrix();
+        }
+        let (i, j) = game.start;
+        tape.record(
+            Snapshot::new("start", warehouse_frame(&game.matrix))
+                .with_focus((i as usize, j as usize)),
+        );
+        let on_move = |(dir, (i, j)): &Move, matrix: &[Vec<char>]| {
+            tape.record(
+                Snapshot::new(
+                    format!("move {dir:?}"),
+                    warehouse_frame(matrix),
+                )
+                .with_focus((*i as usize, *j as usize)),
+            );
+        };
+        if part == 2 {
+            game.run2(on_move);
+        } else {
+            game.run(on_move);
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -352,23 +440,12 @@ mod tests {
     use super::*;
     use indoc::indoc;
 
-    #[test]
-    fn test_part1() {
-        let input = indoc! {
-            r#"
-            ########
-            #..O.O.#
-            ##@.O..#
-            #...O..#
-            #.#.O..#
-            #...O..#
-            #......#
-            ########
+    fn part2(input: &str) -> isize {
+        Day15::part2(&Day15::parse(input).unwrap()).unwrap()
+    }
 
-            <^^>>>vv<v>>v<<
-            "#
-        };
-        assert_eq!(part1(input), 2028);
+    #[test]
+    fn test_part2() {
         let input = indoc! {
             r#"
             #######
@@ -403,37 +480,6 @@ mod tests {
         };
         assert_eq!(part2(input), 6358);
 
-        let input = indoc! {
-            r#"
-            ##########
-            #..O..O.O#
-            #......O.#
-            #.OO..O.O#
-            #..O@..O.#
-            #O#..O...#
-            #O..O..O.#
-            #.OO.O.OO#
-            #....O...#
-            ##########
-
-            <vv>^<v^>v>^vv^v>v<>v^v<v<^vv<<<^><<><>>v<vvv<>^v^>^<<<><<v<<<v^vv^v>^
-            vvv<<^>^v^^><<>>><>^<<><^vv^^<>vvv<>><^^v>^>vv<>v<<<<v<^v>^<^^>>>^<v<v
-            ><>vv>v^v^<>><>>>><^^>vv>v<^^^>>v^v^<^^>v^^>v^<^v>v<>>v^v^<v>v^^<^^vv<
-            <<v<^>>^^^^>>>v^<>vvv^><v<<<>^^^vv^<vvv>^>v<^^^^v<>^>vvvv><>>v^<<^^^^^
-            ^><^><>>><>^^<<^^v>>><^<v>^<vv>>v>>>^v><>^v><<<<v>>v<v<v>vvv>^<><<>^><
-            ^>><>^v<><^vvv<^^<><v<<<<<><^v<<<><<<^^<v<^^^><^>>^<v^><<<^>>^v<v^v<v^
-            >^>>^v>vv>^<<^v<>><<><<v<<v><>v<^vv<<<>^^v^>^^>>><<^v>>v^v><^^>>^<>vv^
-            <><^^>^^^<><vvvvv^v<v<<>^v<v>v<<^><<><<><<<^^<<<^<<>><<><^^^>^^<>^>v<>
-            ^^>vv<^v^v<vv>^<><v<^v>^^^>>>^^vvv^>vvv<>>>^<^>>>>>^<<^v>^vvv<>^<><<v>
-            v^^>>><<^^<>>^v^<v^vv<>v^<<>^<^v^v><^<<<><<^<v><v<>vv>>v><v^<vv<>v^<<^
-            "#
-        };
-        assert_eq!(part1(input), 10092);
-        assert_eq!(part2(input), 9021);
-    }
-
-    #[test]
-    fn test_part2() {
         let input = indoc! {
             r#"
             #######
@@ -545,4 +591,15 @@ mod tests {
         };
         assert_eq!(part2(input), 715);
     }
+
+    common::example_tests! {
+        Day15;
+        small: part1;
+        larger: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day15;
+        max_size: 8;
+    }
 }
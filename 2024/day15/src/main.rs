@@ -0,0 +1,5 @@
+use y2024_day15::{Day15, INPUT};
+
+fn main() {
+    common::run::<Day15>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,87 @@
+use common::differential::Expected;
+use std::collections::HashSet;
+
+/// Moves the robot the way the puzzle tells it: find everything it would
+/// push, give up if any of that would hit a wall, otherwise shift it all
+/// one tile.
+fn run(mut grid: Vec<Vec<char>>, moves: &str) -> usize {
+    let mut robot = (0..grid.len())
+        .flat_map(|i| (0..grid[0].len()).map(move |j| (i, j)))
+        .find(|&(i, j)| grid[i][j] == '@')
+        .unwrap();
+    for step in moves.chars().filter(|c| !c.is_whitespace()) {
+        let (di, dj) = match step {
+            '^' => (-1, 0),
+            'v' => (1, 0),
+            '<' => (0, -1),
+            _ => (0, 1),
+        };
+        let ahead = |(i, j): (usize, usize)| {
+            (i.wrapping_add_signed(di), j.wrapping_add_signed(dj))
+        };
+        let mut pushed = vec![robot];
+        let mut seen = HashSet::from([robot]);
+        let mut k = 0;
+        let mut blocked = false;
+        while k < pushed.len() {
+            let next = ahead(pushed[k]);
+            k += 1;
+            let mut also = vec![];
+            match grid[next.0][next.1] {
+                '#' => blocked = true,
+                'O' => also.push(next),
+                '[' => also.extend([next, (next.0, next.1 + 1)]),
+                ']' => also.extend([next, (next.0, next.1 - 1)]),
+                _ => {}
+            }
+            for tile in also {
+                if seen.insert(tile) {
+                    pushed.push(tile);
+                }
+            }
+        }
+        if blocked {
+            continue;
+        }
+        let before: Vec<char> =
+            pushed.iter().map(|&(i, j)| grid[i][j]).collect();
+        for &(i, j) in &pushed {
+            grid[i][j] = '.';
+        }
+        for (&tile, ch) in pushed.iter().zip(before) {
+            let (i, j) = ahead(tile);
+            grid[i][j] = ch;
+        }
+        robot = ahead(robot);
+    }
+    let mut total = 0;
+    for (i, row) in grid.iter().enumerate() {
+        for (j, &ch) in row.iter().enumerate() {
+            if ch == 'O' || ch == '[' {
+                total += 100 * i + j;
+            }
+        }
+    }
+    total
+}
+
+pub fn expected(input: &str) -> Expected {
+    let (warehouse, moves) = input.split_once("\n\n").unwrap();
+    let grid: Vec<Vec<char>> = warehouse
+        .lines()
+        .map(|line| line.chars().collect())
+        .collect();
+    let wide = grid
+        .iter()
+        .map(|row| {
+            row.iter()
+                .flat_map(|&ch| match ch {
+                    'O' => ['[', ']'],
+                    '@' => ['@', '.'],
+                    ch => [ch, ch],
+                })
+                .collect()
+        })
+        .collect();
+    Expected::both(run(grid, moves), run(wide, moves))
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day16::Day16);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,64 @@
+use rand::Rng;
+use std::collections::VecDeque;
+
+/// Whether `to` can be reached from `from` without crossing a wall.
+fn connected(
+    grid: &[Vec<char>],
+    from: (usize, usize),
+    to: (usize, usize),
+) -> bool {
+    let mut seen = vec![vec![false; grid[0].len()]; grid.len()];
+    let mut queue = VecDeque::from([from]);
+    while let Some((i, j)) = queue.pop_front() {
+        if (i, j) == to {
+            return true;
+        }
+        for (di, dj) in common::DIRECTIONS {
+            let (i, j) =
+                (i.wrapping_add_signed(di), j.wrapping_add_signed(dj));
+            if grid[i][j] != '#' && !seen[i][j] {
+                seen[i][j] = true;
+                queue.push_back((i, j));
+            }
+        }
+    }
+    false
+}
+
+/// A walled maze of up to `size + 4` by `size + 4` tiles with scattered
+/// walls, so there are often several best paths, and a start S from which
+/// the end E can be reached.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    loop {
+        let (m, n) =
+            (rng.gen_range(3..=size + 4), rng.gen_range(3..=size + 4));
+        let density = rng.gen_range(0.0..0.4);
+        let mut grid: Vec<Vec<char>> = (0..m)
+            .map(|i| {
+                (0..n)
+                    .map(|j| {
+                        let border =
+                            i == 0 || j == 0 || i == m - 1 || j == n - 1;
+                        match border || rng.gen_bool(density) {
+                            true => '#',
+                            false => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut tile = || (rng.gen_range(1..m - 1), rng.gen_range(1..n - 1));
+        let (start, end) = (tile(), tile());
+        if start == end {
+            continue;
+        }
+        grid[start.0][start.1] = 'S';
+        grid[end.0][end.1] = 'E';
+        if connected(&grid, start, end) {
+            return grid
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+        }
+    }
+}
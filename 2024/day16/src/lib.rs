@@ -0,0 +1,266 @@
+use common::viz::{Cell, Frame, Recording, Visualize, GREEN, RED};
+use common::{AocError, Solution};
+use std::cmp::{PartialEq, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use tracing::debug;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+type Coord = (usize, usize);
+type Step = (Direction, Coord);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Ord, PartialOrd)]
+enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// Quarter turns from facing `self` to facing `other`.
+    fn turns(self, other: Direction) -> usize {
+        use Direction::*;
+        match (self, other) {
+            _ if self == other => 0,
+            (Up, Down) | (Down, Up) | (Left, Right) | (Right, Left) => 2,
+            _ => 1,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Tile {
+    Wall,
+    Start,
+    End,
+    Dot,
+    Unique,
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Wall => write!(f, "#"),
+            Tile::Start => write!(f, "S"),
+            Tile::End => write!(f, "E"),
+            Tile::Dot => write!(f, "."),
+            Tile::Unique => write!(f, "O"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Maze {
+    matrix: Vec<Vec<Tile>>,
+    start_pos: Coord,
+    end_pos: Coord,
+    height: usize,
+    width: usize,
+}
+
+impl Display for Maze {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for v in &self.matrix {
+            let row_str = v.iter().map(|n| n.to_string()).collect::<String>();
+            writeln!(f, "{}", row_str)?;
+        }
+        Ok(())
+    }
+}
+
+impl Maze {
+    fn new(matrix: Vec<Vec<Tile>>) -> Result<Self, AocError> {
+        let (height, width) =
+            (matrix.len(), matrix.first().map_or(0, Vec::len));
+        if let Some(row) = matrix.iter().position(|row| row.len() != width) {
+            return Err(AocError::at_line(
+                row + 1,
+                1,
+                format!("expected {width} tiles like the first line"),
+            ));
+        }
+        let mut start_pos = None;
+        let mut end_pos = None;
+
+        (0..height)
+            .flat_map(|i| (0..width).map(move |j| (i, j)))
+            .for_each(|(i, j)| {
+                if matrix[i][j] == Tile::End {
+                    end_pos = Some((i, j));
+                }
+                if matrix[i][j] == Tile::Start {
+                    start_pos = Some((i, j));
+                }
+            });
+
+        Ok(Maze {
+            start_pos: start_pos
+                .ok_or_else(|| AocError::new("the maze has no start S"))?,
+            end_pos: end_pos
+                .ok_or_else(|| AocError::new("the maze has no end E"))?,
+            matrix,
+            height,
+            width,
+        })
+    }
+
+    fn get_next_coords(&self, coord: Coord) -> Vec<Step> {
+        [
+            (Direction::Up, (coord.0.wrapping_sub(1), coord.1)),
+            (Direction::Down, (coord.0 + 1, coord.1)),
+            (Direction::Left, (coord.0, coord.1.wrapping_sub(1))),
+            (Direction::Right, (coord.0, coord.1 + 1)),
+        ]
+        .into_iter()
+        .filter(|&(_, (i, j))| {
+            i < self.height
+                && j < self.width
+                && [Tile::End, Tile::Dot].contains(&self.matrix[i][j])
+        })
+        .collect()
+    }
+
+    /// The lowest score and the tiles on any path that has it.
+    fn best_paths(&self) -> Result<(usize, HashSet<Coord>), AocError> {
+        let step: Step = (Direction::Right, self.start_pos);
+        let mut dp: HashMap<Step, usize> = HashMap::new();
+        let mut heap = BinaryHeap::new();
+        let mut best = HashSet::new();
+        let path = vec![self.start_pos];
+        let mut min_score = usize::MAX;
+
+        heap.push(Reverse((0, step, path)));
+        while let Some(Reverse((current_score, current_step, current_path))) =
+            heap.pop()
+        {
+            // Worse paths queued before the end was first reached still
+            // come off the heap afterwards
+            if current_score > min_score {
+                break;
+            }
+            if current_step.1 == self.end_pos {
+                best.extend(current_path.clone());
+                min_score = current_score;
+            }
+            dp.insert(current_step, current_score);
+            for next_step in self.get_next_coords(current_step.1) {
+                let next_score = current_score
+                    + 1
+                    + 1000 * current_step.0.turns(next_step.0);
+                if next_score < *dp.get(&next_step).unwrap_or(&usize::MAX)
+                    && next_score <= min_score
+                {
+                    let mut next_path = current_path.clone();
+                    next_path.push(next_step.1);
+                    heap.push(Reverse((next_score, next_step, next_path)));
+                }
+            }
+        }
+
+        if min_score == usize::MAX {
+            return Err(AocError::new("the end E cannot be reached"));
+        }
+        Ok((min_score, best))
+    }
+
+    fn run(&self) -> Result<(usize, usize), AocError> {
+        let (min_score, best) = self.best_paths()?;
+        let mut maze = self.clone();
+        for (i, j) in best.iter() {
+            maze.matrix[*i][*j] = Tile::Unique;
+        }
+        debug!("tiles on a best path:\n{maze}");
+        Ok((min_score, best.len()))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Maze;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let matrix = input
+            .lines()
+            .enumerate()
+            .map(|(row, line)| {
+                line.chars()
+                    .enumerate()
+                    .map(|(col, c)| match c {
+                        '#' => Ok(Tile::Wall),
+                        '.' => Ok(Tile::Dot),
+                        'S' => Ok(Tile::Start),
+                        'E' => Ok(Tile::End),
+                        _ => Err(AocError::unexpected(c, row, col)),
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<Tile>>, _>>()?;
+
+        Maze::new(matrix)
+    }
+
+    fn part1(maze: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(maze.run()?.0)
+    }
+
+    fn part2(maze: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(maze.run()?.1)
+    }
+}
+
+impl Visualize for Day16 {
+    /// The maze with every tile on a best path marked.
+    fn visualize(
+        maze: &Self::Input<'_>,
+        recording: &mut Recording,
+    ) -> Result<(), AocError> {
+        let (_, best) = maze.best_paths()?;
+        let frame = Frame::from_fn(maze.height, maze.width, |i, j| match maze
+            .matrix[i][j]
+        {
+            Tile::Start => Cell::colored('S', RED),
+            Tile::End => Cell::colored('E', RED),
+            _ if best.contains(&(i, j)) => Cell::colored('O', GREEN),
+            Tile::Wall => Cell::from('#'),
+            _ => Cell::from('.'),
+        });
+        recording.finish(frame);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day16;
+        example1: part1, part2;
+        example2: part1, part2;
+    }
+
+    #[test]
+    fn test_parse_ragged_maze() {
+        let err = Day16::parse("###\n#S#\n#E#\n##\n###\n").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4, column 1: expected 3 tiles like the first line"
+        );
+    }
+
+    common::generator_tests! {
+        Day16;
+        max_size: 8;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day16::{Day16, INPUT};
+
+fn main() {
+    common::run::<Day16>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
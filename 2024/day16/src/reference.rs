@@ -0,0 +1,92 @@
+use common::differential::Expected;
+use std::collections::HashSet;
+
+const EAST: usize = 1;
+
+/// A tile and a heading.
+type State = (usize, usize, usize);
+
+/// Lowers `cost` of every state, a tile and a heading indexing
+/// `common::DIRECTIONS`, until nothing changes. A move goes from `state`
+/// to the states `next` gives, at the price it gives.
+fn relax(
+    cost: &mut [Vec<Vec<usize>>],
+    next: impl Fn(State) -> Vec<(State, usize)>,
+) {
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for i in 0..cost.len() {
+            for j in 0..cost[0].len() {
+                for d in 0..4 {
+                    if cost[i][j][d] == usize::MAX {
+                        continue;
+                    }
+                    for ((ni, nj, nd), price) in next((i, j, d)) {
+                        if cost[i][j][d] + price < cost[ni][nj][nd] {
+                            cost[ni][nj][nd] = cost[i][j][d] + price;
+                            changed = true;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Relaxes the cost of reaching every state from the start facing east,
+/// and of reaching the end from every state by walking backwards. A tile
+/// is on a best path if some heading on it adds up to the lowest score.
+pub fn expected(input: &str) -> Expected {
+    let grid: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let (m, n) = (grid.len(), grid[0].len());
+    let find = |c: char| {
+        (0..m)
+            .flat_map(|i| (0..n).map(move |j| (i, j)))
+            .find(|&(i, j)| grid[i][j] == c)
+            .unwrap()
+    };
+    let (start, end) = (find('S'), find('E'));
+    let step = |(i, j): (usize, usize), d: usize, sign: isize| {
+        let (di, dj) = common::DIRECTIONS[d];
+        let (i, j) = (
+            i.wrapping_add_signed(sign * di),
+            j.wrapping_add_signed(sign * dj),
+        );
+        (grid[i][j] != '#').then_some((i, j, d))
+    };
+    // `common::DIRECTIONS` runs down, right, up, left, so a quarter turn
+    // either way is one index up or down
+    let turns = |i, j, d: usize| {
+        [((i, j, (d + 1) % 4), 1000), ((i, j, (d + 3) % 4), 1000)]
+    };
+
+    let mut from_start = vec![vec![vec![usize::MAX; 4]; n]; m];
+    from_start[start.0][start.1][EAST] = 0;
+    relax(&mut from_start, |(i, j, d)| {
+        let mut next = turns(i, j, d).to_vec();
+        next.extend(step((i, j), d, 1).map(|state| (state, 1)));
+        next
+    });
+    let mut to_end = vec![vec![vec![usize::MAX; 4]; n]; m];
+    to_end[end.0][end.1] = vec![0; 4];
+    relax(&mut to_end, |(i, j, d)| {
+        let mut next = turns(i, j, d).to_vec();
+        next.extend(step((i, j), d, -1).map(|state| (state, 1)));
+        next
+    });
+
+    let best = *from_start[end.0][end.1].iter().min().unwrap();
+    let tiles: HashSet<(usize, usize)> = (0..m)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .filter(|&(i, j)| {
+            (0..4).any(|d| {
+                from_start[i][j][d] != usize::MAX
+                    && to_end[i][j][d] != usize::MAX
+                    && from_start[i][j][d] + to_end[i][j][d] == best
+            })
+        })
+        .collect();
+    Expected::both(best, tiles.len())
+}
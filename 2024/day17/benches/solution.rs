@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day17::Day17);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,29 @@
+use rand::Rng;
+
+/// A program of up to `size` random instructions, looped like the puzzle's
+/// with `adv` by 1 to 3 bits and a jump back to the start while A is not
+/// zero, so that it always halts.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let mut program = vec![];
+    for _ in 0..rng.gen_range(0..=size) {
+        let opcode = [1, 2, 4, 5, 6, 7][rng.gen_range(0..6)];
+        program.extend([opcode, rng.gen_range(0..7)]);
+    }
+    if rng.gen_bool(0.8) {
+        let at = rng.gen_range(0..=program.len() / 2) * 2;
+        program.splice(at..at, [0, rng.gen_range(1..=3)]);
+        program.extend([3, 0]);
+    }
+    if program.is_empty() {
+        program.extend([5, 4]);
+    }
+    let program: Vec<String> =
+        program.iter().map(ToString::to_string).collect();
+    format!(
+        "Register A: {}\nRegister B: {}\nRegister C: {}\n\nProgram: {}\n",
+        rng.gen_range(0..1 << 20),
+        rng.gen_range(0..8),
+        rng.gen_range(0..8),
+        program.join(",")
+    )
+}
@@ -0,0 +1,459 @@
+use common::parse::{finish, split_once};
+use common::stepper::{Snapshot, StepThrough, Tape};
+use common::viz::Frame;
+use common::{AocError, Solution};
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::{
+    alpha1, newline, u32 as nom_u32, u64 as nom_u64,
+};
+use nom::multi::separated_list1;
+use nom::sequence::{preceded, separated_pair};
+use nom::IResult;
+use std::ops::ControlFlow;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+/// Instructions run between two checks of the budget, since a program may
+/// never halt.
+const CHECK_EVERY: usize = 1 << 12;
+
+/// Instructions `aoc debug` records before it stops a program that may
+/// never halt.
+const MAX_STEPS: usize = 1 << 16;
+
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Instruction {
+    fn new(opcode: u32) -> Self {
+        match opcode {
+            0 => Instruction::Adv,
+            1 => Instruction::Bxl,
+            2 => Instruction::Bst,
+            3 => Instruction::Jnz,
+            4 => Instruction::Bxc,
+            5 => Instruction::Out,
+            6 => Instruction::Bdv,
+            7 => Instruction::Cdv,
+            _ => unreachable!("parse only lets 3-bit opcodes through"),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    programs: Vec<u32>,
+    pointer: usize,
+    output: Vec<u32>,
+    skip_increase: bool,
+}
+
+impl Computer {
+    fn combo(&self, operand: u32) -> u64 {
+        match operand {
+            0..=3 => operand as u64,
+            4 => self.a,
+            5 => self.b,
+            6 => self.c,
+            7 => unreachable!("parse rejects the reserved combo operand 7"),
+            _ => unreachable!("parse only lets 3-bit operands through"),
+        }
+    }
+
+    // a / 2^combo, which is 0 once the shift is wider than the register
+    fn dv(&self, operand: u32) -> u64 {
+        u32::try_from(self.combo(operand))
+            .ok()
+            .and_then(|shift| self.a.checked_shr(shift))
+            .unwrap_or(0)
+    }
+
+    // The program halts when the pointer leaves it, or when it points at the
+    // last number and there is no operand to read.
+    fn halted(&self) -> bool {
+        self.pointer + 1 >= self.programs.len()
+    }
+
+    fn run(&mut self) -> Result<bool, AocError> {
+        self.run_with(|_| ControlFlow::Continue(()))
+    }
+
+    /// Runs the program, calling `before_op` with the computer before each
+    /// instruction, until it halts or `before_op` breaks. Fails once over
+    /// the budget, as a program that loops forever would.
+    fn run_with(
+        &mut self,
+        mut before_op: impl FnMut(&Computer) -> ControlFlow<()>,
+    ) -> Result<bool, AocError> {
+        let mut steps = 0;
+        while !self.halted() {
+            steps += 1;
+            if steps % CHECK_EVERY == 0 {
+                common::budget::check()?;
+            }
+            if before_op(self).is_break() {
+                return Ok(false);
+            }
+            let (opcode, operand) = self.programs
+                [self.pointer..self.pointer + 2]
+                .iter()
+                .copied()
+                .collect_tuple()
+                .unwrap();
+            self.op(opcode, operand);
+            if !self.skip_increase {
+                self.pointer += 2;
+            }
+            self.skip_increase = false;
+        }
+        Ok(self.output == self.programs)
+    }
+
+    fn op(&mut self, opcode: u32, operand: u32) {
+        let instruction = Instruction::new(opcode);
+        match instruction {
+            Instruction::Adv => {
+                self.a = self.dv(operand);
+            }
+            Instruction::Bxl => {
+                self.b ^= operand as u64;
+            }
+            Instruction::Bst => {
+                self.b = self.combo(operand) % 8;
+            }
+            Instruction::Jnz => {
+                if self.a != 0 {
+                    self.pointer = operand as usize;
+                    self.skip_increase = true;
+                }
+            }
+            Instruction::Bxc => {
+                self.b ^= self.c;
+            }
+            Instruction::Out => {
+                self.output.push((self.combo(operand) % 8) as u32);
+            }
+            Instruction::Bdv => {
+                self.b = self.dv(operand);
+            }
+            Instruction::Cdv => {
+                self.c = self.dv(operand);
+            }
+        };
+    }
+}
+
+/// The instruction at `pointer` as text, with its combo operand spelled out.
+fn disassemble(programs: &[u32], pointer: usize) -> String {
+    let (opcode, operand) = (programs[pointer], programs[pointer + 1]);
+    let instruction = Instruction::new(opcode);
+    let operand = match (&instruction, operand) {
+        (Instruction::Bxl | Instruction::Jnz | Instruction::Bxc, _) => {
+            operand.to_string()
+        }
+        (_, 4) => "A".to_string(),
+        (_, 5) => "B".to_string(),
+        (_, 6) => "C".to_string(),
+        (_, operand) => operand.to_string(),
+    };
+    let mnemonic = format!("{instruction:?}").to_lowercase();
+    format!("{mnemonic} {operand}")
+}
+
+impl Computer {
+    /// The registers, the program with the current instruction marked, and
+    /// the output so far.
+    fn panel(&self) -> Vec<String> {
+        let mut panel = vec![
+            format!("A {}", self.a),
+            format!("B {}", self.b),
+            format!("C {}", self.c),
+            String::new(),
+        ];
+        panel.extend((0..self.programs.len() / 2).map(|i| {
+            let marker = if i * 2 == self.pointer { ">" } else { " " };
+            let instruction = disassemble(&self.programs, i * 2);
+            format!("{marker} {:>2}  {instruction}", i * 2)
+        }));
+        panel.push(String::new());
+        panel.push(format!("output {}", self.output.iter().join(",")));
+        panel
+    }
+}
+
+fn parse_register(input: &str) -> IResult<&str, Vec<(&str, u64)>> {
+    separated_list1(
+        newline,
+        preceded(tag("Register "), separated_pair(alpha1, tag(": "), nom_u64)),
+    )(input)
+}
+
+fn parse_program(input: &str) -> IResult<&str, Vec<u32>> {
+    preceded(tag("Program: "), separated_list1(tag(","), nom_u32))(input)
+}
+
+fn parse_computer(input: &str) -> Result<Computer, AocError> {
+    let (register_str, program) = split_once(input, "\n\n")?;
+    let register = finish(parse_register(register_str))?;
+    let instructions: Vec<u32> = finish(parse_program(program))?;
+
+    let names: Vec<&str> = register.iter().map(|(name, _)| *name).collect();
+    if names != ["A", "B", "C"] {
+        return Err(AocError::at(
+            register_str,
+            "expected registers A, B and C in order",
+        ));
+    }
+    if let Some(n) = instructions.iter().find(|&&n| n > 7) {
+        return Err(AocError::at(
+            program,
+            format!("{n} is not a 3-bit number"),
+        ));
+    }
+    if !instructions.len().is_multiple_of(2) {
+        return Err(AocError::at(
+            program,
+            "expected pairs of opcode and operand",
+        ));
+    }
+    // adv, bst, out, bdv and cdv take a combo operand
+    if instructions
+        .chunks(2)
+        .any(|pair| [0, 2, 5, 6, 7].contains(&pair[0]) && pair[1] == 7)
+    {
+        return Err(AocError::at(program, "combo operand 7 is reserved"));
+    }
+
+    Ok(Computer {
+        a: register[0].1,
+        b: register[1].1,
+        c: register[2].1,
+        programs: instructions,
+        ..Computer::default()
+    })
+}
+
+// Every loop of the program shifts A right by three bits and prints one
+// value, so A is rebuilt three bits at a time from the last output back to
+// the first, keeping only the candidates whose output matches the tail of
+// the program. Trying the bits in ascending order finds the smallest A.
+fn find_quine(
+    computer: &Computer,
+    a: u64,
+    index: usize,
+) -> Result<Option<u64>, AocError> {
+    for bits in 0..8 {
+        // Past u64 the larger bits overflow too
+        let Some(candidate) =
+            a.checked_mul(8).and_then(|a| a.checked_add(bits))
+        else {
+            break;
+        };
+        let mut probe = Computer {
+            a: candidate,
+            ..computer.clone()
+        };
+        probe.run()?;
+        if probe.output != computer.programs[index..] {
+            continue;
+        }
+        if index == 0 {
+            return Ok(Some(candidate));
+        }
+        if let Some(a) = find_quine(computer, candidate, index - 1)? {
+            return Ok(Some(a));
+        }
+    }
+    Ok(None)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Computer;
+    type Part1 = String;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_computer(input)
+    }
+
+    fn part1(computer: &Self::Input<'_>) -> Result<String, AocError> {
+        let mut computer = computer.clone();
+        computer.run()?;
+        Ok(computer.output.iter().join(","))
+    }
+
+    fn part2(computer: &Self::Input<'_>) -> Result<u64, AocError> {
+        find_quine(computer, 0, computer.programs.len() - 1)?.ok_or_else(
+            || {
+                AocError::new(
+                    "no value of register A makes the program print itself",
+                )
+            },
+        )
+    }
+}
+
+impl StepThrough for Day17 {
+    /// The program on the registers of the input, one instruction per step,
+    /// up to [`MAX_STEPS`] instructions.
+    fn step_through(
+        computer: &Self::Input<'_>,
+        _part: u8,
+        tape: &mut Tape,
+    ) -> Result<(), AocError> {
+        let snapshot = |computer: &Computer, label: String| {
+            Snapshot::new(label, Frame::from_fn(0, 0, |_, _| ' '.into()))
+                .with_panel(computer.panel())
+        };
+        let mut computer = computer.clone();
+        let mut steps = 0;
+        computer.run_with(|computer| {
+            if steps == MAX_STEPS {
+                return ControlFlow::Break(());
+            }
+            steps += 1;
+            let label = disassemble(&computer.programs, computer.pointer);
+            tape.record(snapshot(computer, label));
+            ControlFlow::Continue(())
+        })?;
+        let label = match computer.halted() {
+            true => "halt".to_string(),
+            false => format!("step limit of {MAX_STEPS} reached"),
+        };
+        tape.record(snapshot(&computer, label));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_register_when_c_is_9() {
+        let mut computer = Computer {
+            c: 9,
+            programs: vec![2, 6],
+            ..Default::default()
+        };
+
+        computer.run().unwrap();
+
+        assert_eq!(computer.b, 1);
+    }
+
+    #[test]
+    fn test_output_for_register_a_10() {
+        let mut computer = Computer {
+            a: 10,
+            programs: vec![5, 0, 5, 1, 5, 4],
+            ..Default::default()
+        };
+
+        computer.run().unwrap();
+
+        assert_eq!(computer.output, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_multiply_when_a_is_2024() {
+        let mut computer = Computer {
+            a: 2024,
+            programs: vec![0, 1, 5, 4, 3, 0],
+            ..Default::default()
+        };
+
+        computer.run().unwrap();
+        assert_eq!(computer.output, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(computer.a, 0);
+    }
+
+    #[test]
+    fn test_subtract_b_from_b() {
+        let mut computer = Computer {
+            b: 29,
+            programs: vec![1, 7],
+            ..Default::default()
+        };
+
+        computer.run().unwrap();
+
+        assert_eq!(computer.b, 26);
+    }
+
+    #[test]
+    fn test_set_register_b_with_b_and_c() {
+        let mut computer = Computer {
+            b: 2024,
+            c: 43690,
+            programs: vec![4, 0],
+            ..Default::default()
+        };
+
+        computer.run().unwrap();
+
+        assert_eq!(computer.b, 44354);
+    }
+
+    #[test]
+    fn test_quine() {
+        let mut computer = Computer {
+            a: 117440,
+            programs: vec![0, 3, 5, 4, 3, 0],
+            ..Default::default()
+        };
+
+        assert!(computer.run().unwrap());
+    }
+
+    #[test]
+    fn test_step_through_a_program_that_never_halts() {
+        let computer = Day17::parse(indoc::indoc! {"
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 3,0
+        "})
+        .unwrap();
+        let mut tape = Tape::default();
+
+        Day17::step_through(&computer, 1, &mut tape).unwrap();
+
+        assert_eq!(tape.len(), MAX_STEPS + 1);
+        let mut cursor = tape.cursor().unwrap();
+        cursor.goto(MAX_STEPS);
+        assert_eq!(cursor.label(), "step limit of 65536 reached");
+    }
+
+    common::example_tests! {
+        Day17;
+        example1: part1;
+        example2: part2;
+    }
+
+    common::generator_tests! {
+        Day17;
+        max_size: 8;
+    }
+}
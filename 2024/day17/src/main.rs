@@ -0,0 +1,5 @@
+use y2024_day17::{Day17, INPUT};
+
+fn main() {
+    common::run::<Day17>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
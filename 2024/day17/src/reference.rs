@@ -0,0 +1,39 @@
+use common::differential::Expected;
+
+/// Interprets the program straight from the puzzle's table of opcodes.
+/// Part 2 searches for a register value that makes the program print
+/// itself, which takes far too long to brute-force and is not checked.
+pub fn expected(input: &str) -> Expected {
+    let numbers: Vec<u64> = input
+        .split(|c: char| !c.is_ascii_digit())
+        .filter(|s| !s.is_empty())
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let (mut a, mut b, mut c) = (numbers[0], numbers[1], numbers[2]);
+    let program = &numbers[3..];
+    let mut ip = 0;
+    let mut output = vec![];
+    while ip + 1 < program.len() {
+        let (opcode, literal) = (program[ip], program[ip + 1]);
+        let combo = match literal {
+            4 => a,
+            5 => b,
+            6 => c,
+            _ => literal,
+        };
+        let shifted = a.checked_shr(combo as u32).unwrap_or(0);
+        ip += 2;
+        match opcode {
+            0 => a = shifted,
+            1 => b ^= literal,
+            2 => b = combo % 8,
+            3 if a != 0 => ip = literal as usize,
+            3 => {}
+            4 => b ^= c,
+            5 => output.push((combo % 8).to_string()),
+            6 => b = shifted,
+            _ => c = shifted,
+        }
+    }
+    Expected::part1(output.join(","))
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day18::Day18);
+
+fn main() {
+    divan::main();
+}
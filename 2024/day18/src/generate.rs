@@ -0,0 +1,68 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::{HashSet, VecDeque};
+
+const SIZE: usize = 71;
+
+/// Whether the exit can be reached past the `fallen` bytes.
+fn open(fallen: &HashSet<(usize, usize)>) -> bool {
+    let mut seen = HashSet::from([(0, 0)]);
+    let mut queue = VecDeque::from([(0, 0)]);
+    while let Some((x, y)) = queue.pop_front() {
+        if (x, y) == (SIZE - 1, SIZE - 1) {
+            return true;
+        }
+        for (dx, dy) in common::DIRECTIONS {
+            let next = (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+            if next.0 < SIZE
+                && next.1 < SIZE
+                && !fallen.contains(&next)
+                && seen.insert(next)
+            {
+                queue.push_back(next);
+            }
+        }
+    }
+    false
+}
+
+/// Bytes for the puzzle's 71 by 71 memory: 1024 that leave the exit open
+/// but include all of a wall across the memory except up to three gaps,
+/// then the gaps with `size` other bytes mixed in, so the exit is cut off
+/// within a few bytes.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    // Away from the edges, where the strip beside the wall is too narrow
+    // to stay open
+    let line = rng.gen_range(10..SIZE - 10);
+    let across = rng.gen_bool(0.5);
+    let mut wall: Vec<(usize, usize)> = (0..SIZE)
+        .map(|k| if across { (k, line) } else { (line, k) })
+        .collect();
+    wall.shuffle(rng);
+    let gaps = wall.split_off(SIZE - rng.gen_range(1..=3));
+
+    let mut cells: Vec<(usize, usize)> = (0..SIZE)
+        .flat_map(|x| (0..SIZE).map(move |y| (x, y)))
+        .filter(|&cell| cell != (0, 0) && cell != (SIZE - 1, SIZE - 1))
+        .filter(|cell| !wall.contains(cell) && !gaps.contains(cell))
+        .collect();
+    let (mut first, rest) = loop {
+        cells.shuffle(rng);
+        let (scattered, rest) = cells.split_at(1024 - wall.len());
+        let first: Vec<(usize, usize)> =
+            wall.iter().chain(scattered).copied().collect();
+        if open(&first.iter().copied().collect()) {
+            break (first, rest);
+        }
+    };
+    first.shuffle(rng);
+    let mut last: Vec<(usize, usize)> =
+        gaps.iter().chain(&rest[..size]).copied().collect();
+    last.shuffle(rng);
+
+    first
+        .iter()
+        .chain(&last)
+        .map(|(x, y)| format!("{x},{y}\n"))
+        .collect()
+}
@@ -0,0 +1,238 @@
+use common::parse::{number, split_once};
+use common::viz::{Cell, Frame, Recording, Visualize, RED};
+use common::{AocError, Matrix, Solution};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{Display, Formatter};
+use tracing::{debug, trace};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+#[derive(Debug, Clone, Default, PartialEq)]
+enum Memory {
+    Corrupted,
+    #[default]
+    Space,
+}
+
+impl Display for Memory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Memory::Corrupted => write!(f, "#"),
+            Memory::Space => write!(f, "."),
+        }
+    }
+}
+
+type Coord = (usize, usize);
+
+fn parse_bytes(input: &str) -> Result<Vec<Coord>, AocError> {
+    input
+        .lines()
+        .map(|line| {
+            let (col, row) = split_once(line, ",")?;
+            Ok((number(row)?, number(col)?))
+        })
+        .collect()
+}
+
+fn run(matrix: &Matrix<Memory>) -> usize {
+    let start = (0, 0);
+    let end = (matrix.height - 1, matrix.width - 1);
+
+    let path: Vec<Coord> = vec![start];
+    let mut queue = BinaryHeap::new();
+    let mut visited: HashMap<Coord, usize> = HashMap::new();
+    queue.push(Reverse((0, start, path)));
+    while let Some(Reverse((curr_score, curr_pos, curr_path))) = queue.pop() {
+        if *visited.get(&curr_pos).unwrap_or(&usize::MAX) <= curr_score {
+            continue;
+        }
+        visited.insert(curr_pos, curr_score);
+        if curr_pos == end {
+            return curr_score;
+        }
+        for (di, dj) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+            let next_pos = (
+                curr_pos.0.checked_add_signed(di),
+                curr_pos.1.checked_add_signed(dj),
+            );
+            if next_pos.0.is_none() || next_pos.1.is_none() {
+                continue;
+            }
+            let next_pos = (next_pos.0.unwrap(), next_pos.1.unwrap());
+            if !matrix.in_bounds(next_pos.0, next_pos.1) {
+                continue;
+            }
+            if matrix[next_pos] == Memory::Space
+                && *visited.get(&next_pos).unwrap_or(&usize::MAX)
+                    > curr_score + 1
+            {
+                let mut path = curr_path.clone();
+                path.push(next_pos);
+                queue.push(Reverse((curr_score + 1, next_pos, path)));
+            }
+        }
+    }
+    usize::MAX
+}
+
+fn corrupt(bytes: &[Coord], size: usize) -> Result<Matrix<Memory>, AocError> {
+    let mut matrix: Matrix<Memory> = Matrix::new(size, size);
+    for &(row, col) in bytes {
+        if !matrix.in_bounds(row, col) {
+            return Err(AocError::new(format!(
+                "byte {col},{row} falls outside the {size} by {size} memory"
+            )));
+        }
+        matrix[(row, col)] = Memory::Corrupted;
+    }
+    Ok(matrix)
+}
+
+fn part1(
+    bytes: &[Coord],
+    size: usize,
+    fallen: usize,
+) -> Result<usize, AocError> {
+    let bytes = bytes.get(..fallen).ok_or_else(|| {
+        AocError::new(format!("expected at least {fallen} bytes"))
+    })?;
+    match run(&corrupt(bytes, size)?) {
+        usize::MAX => Err(AocError::new("the exit cannot be reached")),
+        steps => Ok(steps),
+    }
+}
+
+/// How many bytes have fallen when the exit is first cut off.
+fn cut_off(
+    bytes: &[Coord],
+    size: usize,
+    fallen: usize,
+) -> Result<usize, AocError> {
+    let progress = common::progress::bar(
+        (bytes.len() + 1).saturating_sub(fallen),
+        "day 18 bytes",
+    );
+    for n in progress.wrap_iter(fallen..=bytes.len()) {
+        common::budget::check()?;
+        trace!("{n} bytes have fallen");
+        let matrix = corrupt(&bytes[..n], size)?;
+        if run(&matrix) == usize::MAX {
+            debug!("the exit is cut off:\n{matrix}");
+            return Ok(n);
+        }
+    }
+    Err(AocError::new("the exit is never cut off"))
+}
+
+fn part2(
+    bytes: &[Coord],
+    size: usize,
+    fallen: usize,
+) -> Result<String, AocError> {
+    let (row, col) = bytes[cut_off(bytes, size, fallen)? - 1];
+    Ok(format!("{col},{row}"))
+}
+
+/// The memory with the last byte that fell in red. The bytes must be
+/// inside it, which `corrupt` checks.
+fn memory_frame(bytes: &[Coord], size: usize) -> Frame {
+    let mut frame = Frame::from_fn(size, size, |_, _| Cell::from('.'));
+    for &(row, col) in bytes {
+        frame.set(row, col, '#');
+    }
+    if let Some(&(row, col)) = bytes.last() {
+        frame.set(row, col, Cell::colored('#', RED));
+    }
+    frame
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = Vec<Coord>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        parse_bytes(input)
+    }
+
+    fn part1(bytes: &Self::Input<'_>) -> Result<usize, AocError> {
+        part1(bytes, 71, 1024)
+    }
+
+    fn part2(bytes: &Self::Input<'_>) -> Result<String, AocError> {
+        part2(bytes, 71, 1024)
+    }
+}
+
+impl Visualize for Day18 {
+    /// The bytes falling one by one until the exit is cut off.
+    fn visualize(
+        bytes: &Self::Input<'_>,
+        recording: &mut Recording,
+    ) -> Result<(), AocError> {
+        let (size, n) = (71, cut_off(bytes, 71, 1024)?);
+        for fallen in 0..n {
+            recording.record(|| memory_frame(&bytes[..fallen], size));
+        }
+        recording.finish(memory_frame(&bytes[..n], size));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_example() {
+        let input = indoc! {
+            r#"
+            5,4
+            4,2
+            4,5
+            3,0
+            2,1
+            6,3
+            2,4
+            1,5
+            0,6
+            3,3
+            2,6
+            5,1
+            1,2
+            5,5
+            2,5
+            6,5
+            1,4
+            0,4
+            6,4
+            1,1
+            6,1
+            1,0
+            0,5
+            1,6
+            2,0
+            "#
+        };
+        let bytes = Day18::parse(input).unwrap();
+        assert_eq!(part1(&bytes, 7, 12).unwrap(), 22);
+        assert_eq!(part2(&bytes, 7, 12).unwrap(), "6,1");
+    }
+
+    common::generator_tests! {
+        Day18;
+        // Every seed searches the full memory a dozen times or so
+        seeds: 25;
+        max_size: 10;
+    }
+}
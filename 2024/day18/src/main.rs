@@ -0,0 +1,5 @@
+use y2024_day18::{Day18, INPUT};
+
+fn main() {
+    common::run::<Day18>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,47 @@
+use common::differential::Expected;
+use std::collections::{HashSet, VecDeque};
+
+const SIZE: usize = 71;
+
+/// The fewest steps to the exit past the `fallen` bytes, searching breadth
+/// first.
+fn steps(fallen: &[(usize, usize)]) -> Option<usize> {
+    let fallen: HashSet<_> = fallen.iter().copied().collect();
+    let mut queue = VecDeque::from([((0, 0), 0)]);
+    let mut seen = HashSet::from([(0, 0)]);
+    while let Some(((x, y), steps)) = queue.pop_front() {
+        if (x, y) == (SIZE - 1, SIZE - 1) {
+            return Some(steps);
+        }
+        for (dx, dy) in common::DIRECTIONS {
+            let next = (x.wrapping_add_signed(dx), y.wrapping_add_signed(dy));
+            if next.0 < SIZE
+                && next.1 < SIZE
+                && !fallen.contains(&next)
+                && seen.insert(next)
+            {
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Searches after 1024 bytes, then again after each further byte until
+/// the exit is cut off.
+pub fn expected(input: &str) -> Expected {
+    let bytes: Vec<(usize, usize)> = input
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(',').unwrap();
+            (x.parse().unwrap(), y.parse().unwrap())
+        })
+        .collect();
+    let blocking = (1025..=bytes.len())
+        .find(|&n| steps(&bytes[..n]).is_none())
+        .map(|n| format!("{},{}", bytes[n - 1].0, bytes[n - 1].1));
+    Expected {
+        part1: steps(&bytes[..1024]).map(|steps| steps.to_string()),
+        part2: blocking,
+    }
+}
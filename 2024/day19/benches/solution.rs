@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day19::Day19);
+
+fn main() {
+    divan::main();
+}
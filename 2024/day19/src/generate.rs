@@ -0,0 +1,52 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+use std::collections::BTreeSet;
+
+const COLORS: [char; 5] = ['w', 'u', 'b', 'r', 'g'];
+
+fn stripes(rng: &mut impl Rng, len: usize) -> String {
+    (0..len).map(|_| *COLORS.choose(rng).unwrap()).collect()
+}
+
+/// Up to `size` distinct short towel patterns, then `size` designs. Most
+/// designs are made of the patterns, often in more than one way; some
+/// have a stripe changed.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let patterns: BTreeSet<String> = (0..size)
+        .map(|_| {
+            let len = rng.gen_range(1..=3);
+            stripes(rng, len)
+        })
+        .collect();
+    let patterns: Vec<String> = patterns.into_iter().collect();
+    let designs: Vec<String> = (0..size)
+        .map(|_| {
+            let mut design: String = (0..rng.gen_range(1..=4))
+                .map(|_| patterns.choose(rng).unwrap().as_str())
+                .collect();
+            if rng.gen_bool(0.3) {
+                let i = rng.gen_range(0..design.len());
+                let color = stripes(rng, 1);
+                design.replace_range(i..=i, &color);
+            }
+            design
+        })
+        .collect();
+    format!("{}\n\n{}\n", patterns.join(", "), designs.join("\n"))
+}
+
+/// Like [`input`], but every so often with one more design thousands of
+/// stripes long, up to 64K at `size` 4: a solver that recurses once per
+/// pattern runs out of stack on it. The fuzz tests use this; the reference
+/// is too slow for such designs, so the differential test does not.
+pub fn fuzz_input(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = input(rng, size);
+    if rng.gen_ratio(1, 4) {
+        let (patterns, _) = input.split_once("\n\n").unwrap();
+        let pattern = patterns.split(", ").next().unwrap().to_string();
+        let len = 1 << (12 + size.min(4));
+        input += &pattern.repeat(len / pattern.len());
+        input.push('\n');
+    }
+    input
+}
@@ -0,0 +1,91 @@
+use common::parse::split_once;
+use common::{AocError, Solution};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+/// The ways to make `word` out of `patterns`, or `None` past `usize`.
+/// Counted from the end of the word back, as `ways[i]` for the ways to
+/// make `word[i..]`, so a long word needs no deep recursion.
+fn word_search(patterns: &[&str], word: &str) -> Option<usize> {
+    let word = word.as_bytes();
+    let mut ways = vec![0usize; word.len() + 1];
+    ways[word.len()] = 1;
+    for i in (0..word.len()).rev() {
+        ways[i] = patterns
+            .iter()
+            .filter(|pattern| word[i..].starts_with(pattern.as_bytes()))
+            .try_fold(0usize, |sum, pattern| {
+                sum.checked_add(ways[i + pattern.len()])
+            })?;
+    }
+    Some(ways[0])
+}
+
+fn arrangements<'a>(
+    (patterns, towels): &(Vec<&str>, Vec<&'a str>),
+) -> Vec<(&'a str, Option<usize>)> {
+    towels
+        .iter()
+        .map(|&towel| (towel, word_search(patterns, towel)))
+        .collect()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input<'a> = (Vec<&'a str>, Vec<&'a str>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let (patterns, towels) = split_once(input, "\n\n")?;
+        let patterns: Vec<&str> = patterns.split(", ").collect();
+        // An empty pattern would match forever without using up the towel
+        if let Some(empty) = patterns.iter().find(|pattern| pattern.is_empty())
+        {
+            return Err(AocError::at(empty, "expected a towel pattern"));
+        }
+        let towels: Vec<&str> = towels.lines().collect();
+        Ok((patterns, towels))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<usize, AocError> {
+        // Too many ways to count is still a way
+        Ok(arrangements(input)
+            .iter()
+            .filter(|(_, ways)| *ways != Some(0))
+            .count())
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<usize, AocError> {
+        arrangements(input).into_iter().try_fold(
+            0usize,
+            |sum, (towel, ways)| {
+                ways.and_then(|ways| sum.checked_add(ways)).ok_or_else(|| {
+                    AocError::at(towel, "the arrangements add up past usize")
+                })
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day19;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day19;
+        max_size: 8;
+        fuzz_input: generate::fuzz_input;
+    }
+}
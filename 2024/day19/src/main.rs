@@ -0,0 +1,5 @@
+use y2024_day19::{Day19, INPUT};
+
+fn main() {
+    common::run::<Day19>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
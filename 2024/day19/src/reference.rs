@@ -0,0 +1,24 @@
+use common::differential::Expected;
+
+/// Every way of splitting `design` into patterns, tried one by one.
+fn ways(patterns: &[&str], design: &str) -> usize {
+    if design.is_empty() {
+        return 1;
+    }
+    patterns
+        .iter()
+        .filter_map(|pattern| design.strip_prefix(pattern))
+        .map(|rest| ways(patterns, rest))
+        .sum()
+}
+
+pub fn expected(input: &str) -> Expected {
+    let (patterns, designs) = input.split_once("\n\n").unwrap();
+    let patterns: Vec<&str> = patterns.split(", ").collect();
+    let ways: Vec<usize> = designs
+        .lines()
+        .map(|design| ways(&patterns, design))
+        .collect();
+    let possible = ways.iter().filter(|&&n| n > 0).count();
+    Expected::both(possible, ways.iter().sum::<usize>())
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day2::Day2);
+
+fn main() {
+    divan::main();
+}
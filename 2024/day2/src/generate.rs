@@ -0,0 +1,26 @@
+use rand::Rng;
+
+/// `size` reports of one to eight levels. Most climb or fall steadily, some
+/// with one level knocked out of line, so every verdict turns up.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let len = rng.gen_range(1..=8);
+            let sign = if rng.gen_bool(0.5) { 1 } else { -1 };
+            let mut level: isize = rng.gen_range(20..=60);
+            let mut levels: Vec<isize> = (0..len)
+                .map(|_| {
+                    level += sign * rng.gen_range(1..=3);
+                    level
+                })
+                .collect();
+            if rng.gen_bool(0.6) {
+                let i = rng.gen_range(0..len);
+                levels[i] += rng.gen_range(-5..=5);
+            }
+            let levels: Vec<String> =
+                levels.iter().map(ToString::to_string).collect();
+            levels.join(" ") + "\n"
+        })
+        .collect()
+}
@@ -0,0 +1,89 @@
+use common::parse::number;
+use common::{AocError, Solution};
+use itertools::Itertools;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+trait SafeCheck: Iterator<Item = isize> {
+    fn safe(self) -> bool
+    where
+        Self: Sized,
+    {
+        // Check each step on its own rather than pairs of steps, which a
+        // report of two levels does not have
+        self.tuple_windows()
+            .map(|(a, b)| {
+                (1 <= a.abs_diff(b) && 3 >= a.abs_diff(b), a.cmp(&b))
+            })
+            .try_fold(None, |sign, (ok, step)| match sign {
+                Some(sign) if sign != step => None,
+                _ => ok.then_some(Some(step)),
+            })
+            .is_some()
+    }
+}
+
+impl<T> SafeCheck for T where T: Iterator<Item = isize> {}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input<'a> = Vec<Vec<isize>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input
+            .lines()
+            .map(|line| line.split_whitespace().map(number).collect())
+            .collect()
+    }
+
+    fn part1(reports: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(reports
+            .iter()
+            .filter(|report| report.iter().copied().safe())
+            .count())
+    }
+
+    fn part2(reports: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(reports
+            .iter()
+            .filter(|origin| {
+                if origin.iter().copied().safe() {
+                    return true;
+                }
+                for i in 0..origin.len() {
+                    if [&origin[..i], &origin[i + 1..]]
+                        .concat()
+                        .into_iter()
+                        .safe()
+                    {
+                        return true;
+                    }
+                }
+                false
+            })
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day2;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day2;
+        max_size: 30;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day2::{Day2, INPUT};
+
+fn main() {
+    common::run::<Day2>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
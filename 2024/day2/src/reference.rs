@@ -0,0 +1,31 @@
+use common::differential::Expected;
+
+fn safe(levels: &[isize]) -> bool {
+    let steps: Vec<isize> = levels.windows(2).map(|w| w[1] - w[0]).collect();
+    steps.iter().all(|step| (1..=3).contains(step))
+        || steps.iter().all(|step| (-3..=-1).contains(step))
+}
+
+/// Checks every report as is and with each level left out in turn.
+pub fn expected(input: &str) -> Expected {
+    let reports: Vec<Vec<isize>> = input
+        .lines()
+        .map(|line| {
+            line.split_whitespace()
+                .map(|n| n.parse().unwrap())
+                .collect()
+        })
+        .collect();
+    let part1 = reports.iter().filter(|report| safe(report)).count();
+    let part2 = reports
+        .iter()
+        .filter(|report| {
+            (0..report.len()).any(|skip| {
+                let mut report = report.to_vec();
+                report.remove(skip);
+                safe(&report)
+            }) || safe(report)
+        })
+        .count();
+    Expected::both(part1, part2)
+}
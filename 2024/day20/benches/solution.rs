@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day20::Day20);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,54 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// A racetrack with a single path from S to E. A maze is carved through
+/// rooms on every other row and column by a depth-first search, and the
+/// track is its path from the first room to the deepest, so it winds back
+/// and forth past itself with thin walls between to cheat through. `size`
+/// adds rooms across, and the track runs to a few hundred tiles.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let (rows, cols) =
+        (rng.gen_range(3..=8), rng.gen_range(size + 5..=size + 25));
+    let start = (rng.gen_range(0..rows), rng.gen_range(0..cols));
+    let mut parent = vec![vec![None; cols]; rows];
+    parent[start.0][start.1] = Some(start);
+    let (mut stack, mut deepest) = (vec![start], vec![start]);
+    while let Some(&room) = stack.last() {
+        let mut next: Vec<(usize, usize)> = common::DIRECTIONS
+            .iter()
+            .map(|&(di, dj)| {
+                (
+                    room.0.wrapping_add_signed(di),
+                    room.1.wrapping_add_signed(dj),
+                )
+            })
+            .filter(|&(i, j)| i < rows && j < cols && parent[i][j].is_none())
+            .collect();
+        next.shuffle(rng);
+        match next.first() {
+            Some(&(i, j)) => {
+                parent[i][j] = Some(room);
+                stack.push((i, j));
+                if stack.len() > deepest.len() {
+                    deepest = stack.clone();
+                }
+            }
+            None => {
+                stack.pop();
+            }
+        }
+    }
+
+    let mut grid = vec![vec!['#'; cols * 2 + 1]; rows * 2 + 1];
+    for pair in deepest.windows(2) {
+        let ((i, j), (k, l)) = (pair[0], pair[1]);
+        grid[i * 2 + 1][j * 2 + 1] = '.';
+        grid[i + k + 1][j + l + 1] = '.';
+    }
+    let end = deepest[deepest.len() - 1];
+    grid[start.0 * 2 + 1][start.1 * 2 + 1] = 'S';
+    grid[end.0 * 2 + 1][end.1 * 2 + 1] = 'E';
+    grid.iter()
+        .map(|row| row.iter().collect::<String>() + "\n")
+        .collect()
+}
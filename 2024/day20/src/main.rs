@@ -0,0 +1,5 @@
+use y2024_day20::{Day20, INPUT};
+
+fn main() {
+    common::run::<Day20>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,39 @@
+use common::differential::Expected;
+
+/// Follows the track from S to numbering its tiles, then tries every pair
+/// of tiles as the start and end of a cheat.
+pub fn expected(input: &str) -> Expected {
+    let grid: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let mut at = (0..grid.len())
+        .flat_map(|i| (0..grid[0].len()).map(move |j| (i, j)))
+        .find(|&(i, j)| grid[i][j] == 'S')
+        .unwrap();
+    let mut track = vec![at];
+    while grid[at.0][at.1] != 'E' {
+        at = common::DIRECTIONS
+            .iter()
+            .map(|&(di, dj)| {
+                (at.0.wrapping_add_signed(di), at.1.wrapping_add_signed(dj))
+            })
+            .find(|&(i, j)| {
+                grid[i][j] != '#' && track.iter().rev().nth(1) != Some(&(i, j))
+            })
+            .unwrap();
+        track.push(at);
+    }
+
+    let cheats = |longest: usize| {
+        let mut count = 0;
+        for (from, a) in track.iter().enumerate() {
+            for (to, b) in track.iter().enumerate().skip(from + 1) {
+                let cheat = a.0.abs_diff(b.0) + a.1.abs_diff(b.1);
+                if cheat <= longest && to - from >= cheat + 100 {
+                    count += 1;
+                }
+            }
+        }
+        count
+    };
+    Expected::both(cheats(2), cheats(20))
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day21::Day21);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,13 @@
+use rand::Rng;
+
+/// Up to `size` door codes of one to four digits followed by A.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..rng.gen_range(1..=size))
+        .map(|_| {
+            let digits: String = (0..rng.gen_range(1..=4))
+                .map(|_| char::from(b'0' + rng.gen_range(0..10)))
+                .collect();
+            digits + "A\n"
+        })
+        .collect()
+}
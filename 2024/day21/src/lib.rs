@@ -0,0 +1,397 @@
+use common::parse::number;
+use common::{AocError, Coord, Direction, Matrix, Solution};
+use indoc::indoc;
+use itertools::Itertools;
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use tracing::{debug, trace};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+const EMPTY: char = '.';
+const CONFIRM: char = 'A';
+
+const NUMERIC_PAD: &str = indoc! {
+    r#"
+       789
+       456
+       123
+       .0A
+    "#
+};
+
+const CONTROL_PAD: &str = indoc! {
+    r#"
+    .^A
+    <v>
+    "#
+};
+
+struct Solver {
+    numeric_keypad: Matrix<char>,
+    control_keypad: Matrix<char>,
+}
+
+impl Solver {
+    fn get_min_move(
+        &self,
+        possible_moves: Vec<Vec<Direction>>,
+    ) -> Result<String, AocError> {
+        let possible_ch_moves: Vec<String> = possible_moves
+            .iter()
+            .map(|moves| {
+                moves
+                    .iter()
+                    .map(|&dir| self.numeric_keypad.convert_direction(dir))
+                    .collect::<Result<String, _>>()
+            })
+            .collect::<Result<_, _>>()?;
+        trace!("candidate moves {possible_ch_moves:?}");
+        Ok(possible_ch_moves
+            .into_iter()
+            .max_by_key(|moves| {
+                let contiguous_count = moves
+                    .as_bytes()
+                    .windows(2)
+                    .filter(|pair| pair[0] == pair[1])
+                    .count();
+                contiguous_count
+            })
+            .unwrap_or_default())
+    }
+
+    fn _interpret_two_pos(
+        &self,
+        input: &str,
+        keypad: &Matrix<char>,
+    ) -> Result<String, AocError> {
+        // from '>' to '^'  :  only possible way is <^A
+        // from '^' to '>'  :  only possible way is v>A
+        // from 'A' to 'v'  :  only possible way is <vA
+        // from 'v' to 'A'  :  only possible way is ^>A
+        // hardcode way to do this, very bad way!!!
+        if input == ">^" {
+            return Ok("<^".to_string());
+        }
+        if input == "^>" {
+            return Ok("v>".to_string());
+        }
+        if input == "Av" {
+            return Ok("<v".to_string());
+        }
+        if input == "vA" {
+            return Ok("^>".to_string());
+        }
+
+        let coord_a = keypad.key(input.as_bytes()[0] as char)?;
+        let coord_b = keypad.key(input.as_bytes()[1] as char)?;
+        let possible_moves = keypad.find_shortest_moves(coord_a, coord_b);
+        self.get_min_move(possible_moves)
+    }
+    fn _interpret(
+        &self,
+        input: &str,
+        keypad: &Matrix<char>,
+    ) -> Result<String, AocError> {
+        let mut controls: Vec<char> = input.chars().collect();
+        trace!("interpreting {input}");
+
+        controls.insert(0, CONFIRM);
+        controls
+            .into_iter()
+            .tuple_windows()
+            .map(|(a, b)| self._interpret_two_pos(&format!("{a}{b}"), keypad))
+            .map(|moves| {
+                let mut moves = moves?;
+                moves.push(CONFIRM);
+                Ok(moves)
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    fn interpret_control(&self, input: &str) -> Result<String, AocError> {
+        self._interpret(input, &self.control_keypad)
+    }
+
+    #[cfg(test)]
+    fn interpret_code(&self, input: &str) -> Result<String, AocError> {
+        self._interpret(input, &self.numeric_keypad)
+    }
+
+    /// Every shortest way to move the arm of `keypad` from `from` to `to`
+    /// and press it. Which of them is cheapest depends on the robots
+    /// further up, so all of them have to be tried.
+    fn candidates(
+        &self,
+        from: char,
+        to: char,
+        keypad: &Matrix<char>,
+    ) -> Result<Vec<String>, AocError> {
+        let moves =
+            keypad.find_shortest_moves(keypad.key(from)?, keypad.key(to)?);
+        if moves.is_empty() {
+            return Ok(vec![CONFIRM.to_string()]);
+        }
+        moves
+            .iter()
+            .map(|moves| {
+                let mut moves: String = moves
+                    .iter()
+                    .map(|&dir| keypad.convert_direction(dir))
+                    .collect::<Result<_, _>>()?;
+                moves.push(CONFIRM);
+                Ok(moves)
+            })
+            .collect()
+    }
+}
+
+trait KeyPad {
+    fn get_pos(&self, ch: char) -> Option<Coord>;
+    fn find_shortest_moves(
+        &self,
+        start: Coord,
+        end: Coord,
+    ) -> Vec<Vec<Direction>>;
+    fn convert_direction(&self, dir: Direction) -> Result<char, AocError>;
+
+    /// Where `ch` is on the keypad, or an error if it has no such key.
+    fn key(&self, ch: char) -> Result<Coord, AocError> {
+        self.get_pos(ch).ok_or_else(|| {
+            AocError::new(format!("no {ch:?} key on the keypad"))
+        })
+    }
+}
+
+impl KeyPad for Matrix<char> {
+    fn get_pos(&self, ch: char) -> Option<Coord> {
+        for i in 0..self.height {
+            for j in 0..self.width {
+                if self.matrix[i][j] == ch {
+                    return Some((i, j));
+                }
+            }
+        }
+        None
+    }
+
+    fn find_shortest_moves(
+        &self,
+        start: Coord,
+        end: Coord,
+    ) -> Vec<Vec<Direction>> {
+        if start == end {
+            return vec![];
+        }
+        let mut queue = BinaryHeap::new();
+        let mut distance: HashMap<Coord, usize> = HashMap::new();
+        let mut moves = vec![];
+        queue.push(Reverse((0, start, vec![])));
+        while let Some(Reverse((curr_score, curr_pos, curr_moves))) =
+            queue.pop()
+        {
+            if *distance.get(&curr_pos).unwrap_or(&usize::MAX) < curr_score {
+                continue;
+            }
+            distance.insert(curr_pos, curr_score);
+            if curr_pos == end {
+                moves.push(curr_moves.clone());
+            }
+            for (next_pos, dir) in self.get_coord_neighbours(curr_pos) {
+                if *distance.get(&next_pos).unwrap_or(&usize::MAX) > curr_score
+                    && self[next_pos] != EMPTY
+                {
+                    let mut curr_moves_clone = curr_moves.clone();
+                    curr_moves_clone.push(dir);
+                    queue.push(Reverse((
+                        curr_score + 1,
+                        next_pos,
+                        curr_moves_clone,
+                    )));
+                }
+            }
+        }
+        moves
+    }
+
+    fn convert_direction(
+        &self,
+        direction: Direction,
+    ) -> Result<char, AocError> {
+        match direction {
+            (-1, 0) => Ok('^'),
+            (1, 0) => Ok('v'),
+            (0, 1) => Ok('>'),
+            (0, -1) => Ok('<'),
+            _ => Err(AocError::new(format!(
+                "no arrow key moves the arm by {direction:?}"
+            ))),
+        }
+    }
+}
+
+/// A door code and the number it starts with.
+type Code<'a> = (&'a str, usize);
+
+fn solve(codes: &[Code], n: usize) -> Result<usize, AocError> {
+    let numeric_keypad = Matrix::from(NUMERIC_PAD, |c: char| c)?;
+    let control_keypad = Matrix::from(CONTROL_PAD, |c: char| c)?;
+    let solver = Solver {
+        numeric_keypad,
+        control_keypad,
+    };
+    /// The fewest of the presses for each way to move an arm.
+    fn fewest(presses: Vec<usize>) -> Result<usize, AocError> {
+        presses
+            .into_iter()
+            .min()
+            .ok_or_else(|| AocError::new("no way to move the arm"))
+    }
+
+    /// Presses for `input` through `times` more robots, remembered in
+    /// `seen` for this solve only.
+    fn recursion(
+        input: &str,
+        times: usize,
+        solver: &Solver,
+        seen: &mut HashMap<(String, usize), usize>,
+    ) -> Result<usize, AocError> {
+        let key = (input.to_string(), times);
+        if let Some(&presses) = seen.get(&key) {
+            return Ok(presses);
+        }
+        let mut total = 0;
+        let input = if times != 0 {
+            format!("{}{}", CONFIRM, input)
+        } else {
+            input.to_string()
+        };
+        if times == 0 {
+            let last = &solver._interpret(&input, &solver.control_keypad)?;
+            trace!("last: {last}");
+            seen.insert(key, last.len());
+            return Ok(last.len());
+        }
+        for (from, to) in input.chars().tuple_windows() {
+            let presses = solver
+                .candidates(from, to, &solver.control_keypad)?
+                .iter()
+                .map(|go| {
+                    trace!("input: {input}, times: {times}, go: {go}");
+                    recursion(go, times - 1, solver, seen)
+                })
+                .collect::<Result<_, _>>()?;
+            total += fewest(presses)?;
+        }
+        seen.insert(key, total);
+        Ok(total)
+    }
+
+    let mut seen = HashMap::new();
+    let nums: Vec<usize> = codes
+        .iter()
+        .map(|(line, _)| {
+            format!("{CONFIRM}{line}")
+                .chars()
+                .tuple_windows()
+                .map(|(from, to)| {
+                    let presses = solver
+                        .candidates(from, to, &solver.numeric_keypad)?
+                        .iter()
+                        .map(|moves| recursion(moves, n, &solver, &mut seen))
+                        .collect::<Result<_, _>>()?;
+                    fewest(presses)
+                })
+                .sum()
+        })
+        .collect::<Result<_, _>>()?;
+    debug!("sequence lengths {nums:?}");
+    codes
+        .iter()
+        .zip(nums)
+        .try_fold(0usize, |sum, (&(line, num), length)| {
+            num.checked_mul(length)
+                .and_then(|complexity| sum.checked_add(complexity))
+                .ok_or_else(|| {
+                    AocError::at(line, "the complexities add up past usize")
+                })
+        })
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Vec<Code<'a>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let digits = line.strip_suffix(CONFIRM).unwrap_or("");
+                if digits.is_empty()
+                    || !digits.chars().all(|c| c.is_ascii_digit())
+                {
+                    return Err(AocError::at(
+                        line,
+                        "expected a door code, digits followed by A",
+                    ));
+                }
+                Ok((line, number(digits)?))
+            })
+            .collect()
+    }
+
+    fn part1(codes: &Self::Input<'_>) -> Result<usize, AocError> {
+        solve(codes, 1)
+    }
+
+    fn part2(codes: &Self::Input<'_>) -> Result<usize, AocError> {
+        solve(codes, 24)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keypads() {
+        let numeric_keypad = Matrix::from(NUMERIC_PAD, |c: char| c).unwrap();
+        let control_keypad = Matrix::from(CONTROL_PAD, |c: char| c).unwrap();
+        let solver = Solver {
+            numeric_keypad,
+            control_keypad,
+        };
+
+        assert_eq!(solver.interpret_code("029A").unwrap(), "<A^A>^^AvvvA");
+        assert_eq!(
+            solver.interpret_control("<A^A>^^AvvvA").unwrap(),
+            "v<<A>>^A<A>AvA<^AA>A<vAAA^>A"
+        );
+        assert_eq!(
+            solver
+                .interpret_control("v<<A>>^A<A>AvA<^AA>A<vAAA>^A")
+                .unwrap(),
+            "<vA<AA>>^AvAA<^A>Av<<A>>^AvA^A<vA^>Av<<A>^A>AAvA^Av<<A>A^>AAAvA<^A>A"
+        );
+    }
+
+    common::example_tests! {
+        Day21;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day21;
+        seeds: 300;
+        max_size: 5;
+    }
+}
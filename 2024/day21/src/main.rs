@@ -0,0 +1,5 @@
+use y2024_day21::{Day21, INPUT};
+
+fn main() {
+    common::run::<Day21>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,98 @@
+use common::differential::Expected;
+use std::collections::{HashSet, VecDeque};
+
+const NUMERIC: [&str; 4] = ["789", "456", "123", ".0A"];
+const DIRECTIONAL: [&str; 2] = [".^A", "<v>"];
+
+fn find(pad: &[&str], key: char) -> (usize, usize) {
+    pad.iter()
+        .enumerate()
+        .find_map(|(i, row)| row.find(key).map(|j| (i, j)))
+        .unwrap()
+}
+
+fn key(pad: &[&str], (i, j): (usize, usize)) -> Option<char> {
+    let key = pad.get(i)?.chars().nth(j)?;
+    (key != '.').then_some(key)
+}
+
+/// The arm at `at` on `pad` after `press` is pressed on the keypad
+/// controlling it. Returns the new position and the key pressed with `A`,
+/// or `None` if the arm would leave the keypad.
+fn operate(
+    pad: &[&str],
+    at: (usize, usize),
+    press: char,
+) -> Option<((usize, usize), Option<char>)> {
+    let (di, dj) = match press {
+        '^' => (-1, 0),
+        'v' => (1, 0),
+        '<' => (0, -1),
+        '>' => (0, 1),
+        _ => return Some((at, key(pad, at))),
+    };
+    let next = (at.0.checked_add_signed(di)?, at.1.checked_add_signed(dj)?);
+    key(pad, next).map(|_| (next, None))
+}
+
+/// Searches breadth first over the arms of both directional robots and
+/// the numeric one, and how much of the code is typed, for the fewest
+/// presses on my keypad. Part 2 chains 25 robots, far too many states to
+/// search, and is not checked.
+fn presses(code: &str) -> usize {
+    let code: Vec<char> = code.chars().collect();
+    let a = find(&DIRECTIONAL, 'A');
+    let start = (a, a, find(&NUMERIC, 'A'), 0);
+    let mut queue = VecDeque::from([(start, 0)]);
+    let mut seen = HashSet::from([start]);
+    while let Some(((first, second, numeric, typed), presses)) =
+        queue.pop_front()
+    {
+        if typed == code.len() {
+            return presses;
+        }
+        for press in "^v<>A".chars() {
+            let Some((first, press)) = operate(&DIRECTIONAL, first, press)
+            else {
+                continue;
+            };
+            let mut state = (first, second, numeric, typed);
+            if let Some(press) = press {
+                let Some((second, press)) =
+                    operate(&DIRECTIONAL, second, press)
+                else {
+                    continue;
+                };
+                state.1 = second;
+                if let Some(press) = press {
+                    let Some((numeric, press)) =
+                        operate(&NUMERIC, numeric, press)
+                    else {
+                        continue;
+                    };
+                    state.2 = numeric;
+                    match press {
+                        Some(digit) if digit == code[typed] => state.3 += 1,
+                        Some(_) => continue,
+                        None => {}
+                    }
+                }
+            }
+            if seen.insert(state) {
+                queue.push_back((state, presses + 1));
+            }
+        }
+    }
+    unreachable!("every code can be typed")
+}
+
+pub fn expected(input: &str) -> Expected {
+    let complexity: usize = input
+        .lines()
+        .map(|code| {
+            let number: usize = code.trim_end_matches('A').parse().unwrap();
+            number * presses(code)
+        })
+        .sum();
+    Expected::part1(complexity)
+}
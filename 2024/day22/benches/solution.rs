@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day22::Day22);
+
+fn main() {
+    divan::main();
+}
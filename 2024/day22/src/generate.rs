@@ -0,0 +1,8 @@
+use rand::Rng;
+
+/// Up to `size` buyers' initial secret numbers.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..rng.gen_range(1..=size))
+        .map(|_| format!("{}\n", rng.gen_range(1..16_777_216)))
+        .collect()
+}
@@ -0,0 +1,181 @@
+use common::parse::number;
+use common::{AocError, Solution};
+use itertools::Itertools;
+
+use std::collections::HashMap;
+use tracing::{debug, trace};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn mix(secret: i64, value: i64) -> i64 {
+    secret ^ value
+}
+
+fn prune(secret: i64) -> i64 {
+    secret % 16777216
+}
+
+/// The next secret after `secret`, remembered in `seen` for this solve
+/// only.
+fn evolve_secret(secret: i64, seen: &mut HashMap<i64, i64>) -> i64 {
+    *seen.entry(secret).or_insert_with(|| evolve(secret))
+}
+
+fn evolve(mut secret: i64) -> i64 {
+    // Step 1: Multiply by 64
+    let result = secret * 64;
+    secret = mix(secret, result);
+    secret = prune(secret);
+
+    // Step 2: Divide by 32 and round down
+    let result = secret / 32;
+    secret = mix(secret, result);
+    secret = prune(secret);
+
+    // Step 3: Multiply by 2048
+    let result = secret * 2048;
+    secret = mix(secret, result);
+    secret = prune(secret);
+
+    secret
+}
+
+fn evolve_n_times(secret: i64, n: usize, seen: &mut HashMap<i64, i64>) -> i64 {
+    (0..n).fold(secret, |secret, _| evolve_secret(secret, seen))
+}
+
+fn take_last(secret: i64) -> i64 {
+    secret.to_string().chars().last().unwrap() as i64 - b'0' as i64
+}
+
+fn part2(secrets: &[i64], n: usize) -> i64 {
+    let mut seen = HashMap::new();
+    let mut total = vec![];
+    for &initial in secrets {
+        let prices: Vec<i64> = (0..n)
+            .fold(vec![initial], |mut acc, _n| {
+                acc.push(evolve_secret(acc[acc.len() - 1], &mut seen));
+                acc
+            })
+            .into_iter()
+            .map(take_last)
+            .collect();
+        let changes: Vec<i64> =
+            prices.iter().tuple_windows().map(|(a, b)| b - a).collect();
+        let sequences =
+            (0..changes.len() - 3).fold(HashMap::new(), |mut sequences, i| {
+                let key = format!("{:?}", &changes[i..i + 4]);
+                if sequences.contains_key(&key) {
+                    return sequences;
+                }
+                sequences.insert(key, prices[i + 4]);
+                sequences
+            });
+        total.push(sequences);
+    }
+    debug!("collected the sequences of {} buyers", total.len());
+    let counter: HashMap<String, i64> =
+        total.iter().fold(HashMap::new(), |mut counter, sequences| {
+            for (key, value) in sequences {
+                *counter.entry(key.clone()).or_default() += value;
+            }
+            counter
+        });
+    trace!("{} distinct sequences", counter.len());
+
+    counter.values().max().copied().unwrap_or(0)
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let secret = number(line)?;
+                // Secrets are pruned below 16777216 from the first step on
+                match (0..16777216).contains(&secret) {
+                    true => Ok(secret),
+                    false => Err(AocError::at(
+                        line,
+                        "expected a secret number below 16777216",
+                    )),
+                }
+            })
+            .collect()
+    }
+
+    fn part1(secrets: &Self::Input<'_>) -> Result<i64, AocError> {
+        let mut seen = HashMap::new();
+        Ok(secrets
+            .iter()
+            .map(|&secret| evolve_n_times(secret, 2000, &mut seen))
+            .sum())
+    }
+
+    fn part2(secrets: &Self::Input<'_>) -> Result<i64, AocError> {
+        Ok(part2(secrets, 2000))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mix() {
+        assert_eq!(mix(42, 15), 37);
+    }
+
+    #[test]
+    fn test_prune() {
+        assert_eq!(prune(100000000), 16113920);
+    }
+
+    #[test]
+    fn test_evolution() {
+        let secret = 42;
+        let evolved = evolve_secret(secret, &mut HashMap::new());
+        assert!(evolved < 16777216);
+    }
+
+    #[test]
+    fn test_evolution_n_times() {
+        let secret = 123;
+        let evolved = evolve_n_times(secret, 10, &mut HashMap::new());
+        assert_eq!(evolved, 5908254);
+    }
+
+    #[test]
+    fn test_take_last() {
+        let secret = 15887950;
+        assert_eq!(take_last(secret), 0);
+    }
+
+    #[test]
+    fn test_part2() {
+        assert_eq!(part2(&Day22::parse("123").unwrap(), 10), 6);
+    }
+
+    common::example_tests! {
+        Day22;
+        example1: part1;
+        example2: part2;
+    }
+
+    common::generator_tests! {
+        Day22;
+        seeds: 100;
+        max_size: 4;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day22::{Day22, INPUT};
+
+fn main() {
+    common::run::<Day22>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,36 @@
+use common::differential::Expected;
+
+fn next(mut secret: u64) -> u64 {
+    secret = (secret ^ (secret << 6)) & 0xFF_FFFF;
+    secret = (secret ^ (secret >> 5)) & 0xFF_FFFF;
+    (secret ^ (secret << 11)) & 0xFF_FFFF
+}
+
+/// Follows every buyer's 2000 secrets, and for part 2 adds each buyer's
+/// price at the first time each run of four changes appears into a table
+/// of every possible run, which is small enough to walk in full.
+pub fn expected(input: &str) -> Expected {
+    let mut sum = 0;
+    let mut bananas = vec![0; 19usize.pow(4)];
+    for line in input.lines() {
+        let mut secret: u64 = line.parse().unwrap();
+        let mut prices = vec![secret % 10];
+        for _ in 0..2000 {
+            secret = next(secret);
+            prices.push(secret % 10);
+        }
+        sum += secret;
+
+        let mut seen = vec![false; bananas.len()];
+        for window in prices.windows(5) {
+            let run = window
+                .windows(2)
+                .fold(0, |run, pair| run * 19 + (pair[1] + 9 - pair[0]));
+            if !seen[run as usize] {
+                seen[run as usize] = true;
+                bananas[run as usize] += window[4];
+            }
+        }
+    }
+    Expected::both(sum, bananas.iter().max().unwrap())
+}
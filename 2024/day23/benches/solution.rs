@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day23::Day23);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,31 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// A network of `size + 3` computers with a few random links and one
+/// planted LAN party, written one link per line in a random order.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let mut names: Vec<String> = Vec::new();
+    while names.len() < size + 3 {
+        // Lean towards `t` so that part 1 has triples to count
+        let first = match rng.gen_bool(0.3) {
+            true => 't',
+            false => rng.gen_range(b'a'..=b'z') as char,
+        };
+        let name = format!("{first}{}", rng.gen_range(b'a'..=b'z') as char);
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+
+    let mut links = Vec::new();
+    let party = rng.gen_range(2..=names.len().min(6));
+    for (i, a) in names.iter().enumerate() {
+        for (j, b) in names.iter().enumerate().skip(i + 1) {
+            if j < party || rng.gen_bool(0.3) {
+                links.push(format!("{a}-{b}\n"));
+            }
+        }
+    }
+    links.shuffle(rng);
+    links.concat()
+}
@@ -0,0 +1,131 @@
+use common::parse::split_once;
+use common::{AocError, Solution};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+type Network<'a> = HashMap<&'a str, HashSet<&'a str>>;
+
+fn get_computer_networks(input: &str) -> Result<Network<'_>, AocError> {
+    let mut map: Network = HashMap::new();
+    for line in input.lines() {
+        let (a, b) = split_once(line, "-")?;
+        map.entry(a).or_default().insert(b);
+        map.entry(b).or_default().insert(a);
+    }
+    Ok(map)
+}
+
+/// Grows `clique` with every computer in `candidates` in turn, skipping
+/// those linked to the pivot, which a later branch reaches anyway, and keeps
+/// the largest clique found in `largest` (Bron–Kerbosch). A dense network
+/// has many cliques, so every branch checks the budget.
+fn grow_clique<'a>(
+    graph: &Network<'a>,
+    clique: &mut Vec<&'a str>,
+    mut candidates: HashSet<&'a str>,
+    mut excluded: HashSet<&'a str>,
+    largest: &mut Vec<&'a str>,
+) -> Result<(), AocError> {
+    common::budget::check()?;
+    if candidates.is_empty() && excluded.is_empty() {
+        if clique.len() > largest.len() {
+            *largest = clique.clone();
+        }
+        return Ok(());
+    }
+    let pivot = candidates
+        .iter()
+        .chain(&excluded)
+        .max_by_key(|&&vertex| graph[vertex].len())
+        .copied()
+        .expect("checked not empty above");
+    let branches: Vec<&str> =
+        candidates.difference(&graph[pivot]).copied().collect();
+    for vertex in branches {
+        let neighbours = &graph[vertex];
+        clique.push(vertex);
+        grow_clique(
+            graph,
+            clique,
+            candidates.intersection(neighbours).copied().collect(),
+            excluded.intersection(neighbours).copied().collect(),
+            largest,
+        )?;
+        clique.pop();
+        candidates.remove(vertex);
+        excluded.insert(vertex);
+    }
+    Ok(())
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = HashMap<&'a str, HashSet<&'a str>>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        get_computer_networks(input)
+    }
+
+    fn part1(graph: &Self::Input<'_>) -> Result<usize, AocError> {
+        let internets: HashSet<(&str, &str, &str)> = graph
+            .iter()
+            .combinations(2)
+            .filter(|pair| pair[0].1.contains(pair[1].0))
+            .flat_map(|pair| {
+                let (a, na) = pair[0];
+                let (b, nb) = pair[1];
+
+                na.intersection(nb).map(|&common| {
+                    let mut members = [a, b, common];
+                    members.sort_unstable();
+                    (members[0], members[1], members[2])
+                })
+            })
+            .collect();
+        Ok(internets
+            .iter()
+            .filter(|&&tuple| {
+                tuple.0.starts_with('t')
+                    || tuple.1.starts_with('t')
+                    || tuple.2.starts_with('t')
+            })
+            .count())
+    }
+
+    fn part2(graph: &Self::Input<'_>) -> Result<String, AocError> {
+        let mut largest = Vec::new();
+        grow_clique(
+            graph,
+            &mut Vec::new(),
+            graph.keys().copied().collect(),
+            HashSet::new(),
+            &mut largest,
+        )?;
+        Ok(largest.iter().sorted().join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day23;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day23;
+        max_size: 6;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day23::{Day23, INPUT};
+
+fn main() {
+    common::run::<Day23>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,52 @@
+use common::differential::Expected;
+use itertools::Itertools;
+use std::collections::{BTreeSet, HashSet};
+
+/// Tries every triple of computers for part 1 and every subset of them for
+/// part 2. Part 2 is left unchecked when two largest LAN parties tie, as
+/// the puzzle promises there is only one.
+pub fn expected(input: &str) -> Expected {
+    let links: HashSet<(&str, &str)> = input
+        .lines()
+        .flat_map(|line| {
+            let (a, b) = line.split_once('-').unwrap();
+            [(a, b), (b, a)]
+        })
+        .collect();
+    let computers: Vec<&str> = links
+        .iter()
+        .map(|&(a, _)| a)
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect();
+    let party = |members: &[&str]| {
+        members
+            .iter()
+            .tuple_combinations()
+            .all(|(&a, &b)| links.contains(&(a, b)))
+    };
+
+    let triples = computers
+        .iter()
+        .copied()
+        .combinations(3)
+        .filter(|triple| party(triple))
+        .filter(|triple| triple.iter().any(|name| name.starts_with('t')))
+        .count();
+
+    let mut largest: Vec<Vec<&str>> = Vec::new();
+    for members in computers.iter().copied().powerset() {
+        if !party(&members) {
+            continue;
+        }
+        match largest.first().map(|largest| largest.len()) {
+            Some(len) if len > members.len() => {}
+            Some(len) if len == members.len() => largest.push(members),
+            _ => largest = vec![members],
+        }
+    }
+    match largest.as_slice() {
+        [only] => Expected::both(triples, only.join(",")),
+        _ => Expected::part1(triples),
+    }
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day24::Day24);
+
+fn main() {
+    divan::main();
+}
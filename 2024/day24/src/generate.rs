@@ -0,0 +1,46 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// `size` bits each of `x` and `y`, and a random circuit of about three
+/// gates a bit that only reads wires with a signal or gates before it, so
+/// every wire gets one. Some of the gates write the `z` wires.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let mut input = String::new();
+    let mut wires = Vec::new();
+    for prefix in ["x", "y"] {
+        for bit in 0..size {
+            let wire = format!("{prefix}{bit:02}");
+            input.push_str(&format!("{wire}: {}\n", rng.gen_range(0..=1)));
+            wires.push(wire);
+        }
+    }
+    input.push('\n');
+
+    let gates = rng.gen_range(size..=3 * size);
+    let mut outputs: Vec<usize> = (0..gates).collect();
+    outputs.shuffle(rng);
+    outputs.truncate(rng.gen_range(1..=gates));
+
+    let mut lines = Vec::new();
+    for gate in 0..gates {
+        let a = wires.choose(rng).unwrap().clone();
+        let b = wires.choose(rng).unwrap().clone();
+        let op = ["AND", "OR", "XOR"].choose(rng).unwrap();
+        let c = match outputs.iter().position(|&output| output == gate) {
+            Some(bit) => format!("z{bit:02}"),
+            None => loop {
+                let name: String = (0..3)
+                    .map(|_| rng.gen_range(b'a'..=b'w') as char)
+                    .collect();
+                if !wires.contains(&name) {
+                    break name;
+                }
+            },
+        };
+        lines.push(format!("{a} {op} {b} -> {c}\n"));
+        wires.push(c);
+    }
+    lines.shuffle(rng);
+    input.push_str(&lines.concat());
+    input
+}
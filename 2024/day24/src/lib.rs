@@ -0,0 +1,226 @@
+use common::parse::{finish, split_once};
+use common::{AocError, Solution};
+use indoc::formatdoc;
+use itertools::Itertools;
+use nom::bytes::complete::tag;
+use nom::character::complete::u8 as nom_u8;
+use nom::character::complete::{alphanumeric1, space1};
+use nom::sequence::{preceded, separated_pair, tuple};
+use nom::IResult;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn parse_value(input: &str) -> IResult<&str, (&str, u8)> {
+    separated_pair(alphanumeric1, tag(": "), nom_u8)(input)
+}
+
+fn parse_wire(input: &str) -> IResult<&str, ((&str, &str, &str), &str)> {
+    separated_pair(
+        tuple((
+            alphanumeric1,
+            preceded(space1, alphanumeric1),
+            preceded(space1, alphanumeric1),
+        )),
+        tag(" -> "),
+        alphanumeric1,
+    )(input)
+}
+
+#[derive(Debug, Clone)]
+pub struct Wire<'a> {
+    a: &'a str,
+    b: &'a str,
+    c: &'a str,
+    op: &'a str,
+}
+
+impl Wire<'_> {
+    fn reads_inputs(&self) -> bool {
+        [self.a, self.b]
+            .iter()
+            .all(|w| w.starts_with('x') || w.starts_with('y'))
+    }
+
+    fn reads_first_bit(&self) -> bool {
+        [self.a, self.b].iter().all(|w| w.ends_with("00"))
+    }
+}
+
+/// Dumps the circuit as a graphviz digraph, handy for eyeballing which
+/// gates break the adder pattern.
+pub fn write_graph(wires: &[Wire], path: impl AsRef<Path>) -> io::Result<()> {
+    let graph_content = wires.iter().fold(String::new(), |mut s, wire| {
+        let color = match wire.op {
+            "AND" => "blue",
+            "XOR" => "read",
+            "OR" => "green",
+            _ => unreachable!("parse only lets AND, XOR and OR through"),
+        };
+        s.push_str(&format!(
+            "\"{}\" -> \"{}\" [label=\"{}\", color=\"{}\"]\n",
+            wire.a, wire.c, wire.op, color
+        ));
+        s.push_str(&format!(
+            "\"{}\" -> \"{}\" [label=\"{}\", color=\"{}\"]\n",
+            wire.b, wire.c, wire.op, color
+        ));
+        s
+    });
+    let indented_graph_content = graph_content
+        .lines()
+        .map(|line| format!("{}{}", " ".repeat(8), line))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let graph = formatdoc! {
+        "
+        digraph LogicalGraph {{
+            // Define graph properties
+            graph [rankdir=LR];
+            node [shape=ellipse, style=filled, fillcolor=lightblue];
+            {graph_content}
+        }}
+        ", graph_content=indented_graph_content
+    };
+
+    fs::write(path, graph)
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = (HashMap<&'a str, u8>, Vec<Wire<'a>>);
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let (values, wires) = split_once(input, "\n\n")?;
+        let values: HashMap<&str, u8> = values
+            .lines()
+            .map(|line| finish(parse_value(line)))
+            .collect::<Result<_, _>>()?;
+        let wires: Vec<Wire> = wires
+            .lines()
+            .map(|line| {
+                let ((a, op, b), c) = finish(parse_wire(line))?;
+                match op {
+                    "AND" | "XOR" | "OR" => Ok(Wire { a, b, c, op }),
+                    _ => Err(AocError::at(op, "expected AND, XOR or OR")),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok((values, wires))
+    }
+
+    fn part1((values, wires): &Self::Input<'_>) -> Result<usize, AocError> {
+        let mut values = values.clone();
+        let mut wires = wires.clone();
+
+        // Wires put back in a row without a signal; once every wire left
+        // went round, the rest never get one.
+        let mut stalled = 0;
+        while let Some(wire) = wires.pop() {
+            if let (Some(a), Some(b)) =
+                (values.get(wire.a), values.get(wire.b))
+            {
+                let c = match wire.op {
+                    "AND" => a & b,
+                    "XOR" => a ^ b,
+                    "OR" => a | b,
+                    _ => {
+                        unreachable!("parse only lets AND, XOR and OR through")
+                    }
+                };
+                values.insert(wire.c, c);
+                stalled = 0;
+            } else {
+                stalled += 1;
+                if stalled > wires.len() {
+                    return Err(AocError::new(format!(
+                        "wire {} never gets a signal",
+                        wire.c
+                    )));
+                }
+                wires.insert(0, wire);
+            }
+        }
+
+        Ok(values
+            .iter()
+            .filter(|(k, _)| k.starts_with('z'))
+            .sorted_by(|a, b| b.0.cmp(a.0))
+            .map(|(_k, &v)| v)
+            .fold(0_usize, |acc, bit| (acc << 1) | (bit as usize)))
+    }
+
+    // The circuit is a ripple-carry adder, so every gate has a fixed shape:
+    // - a "z" output (except the final carry) comes out of a XOR
+    // - a XOR not fed by x/y must write a "z"
+    // - x XOR y feeds the next XOR, x AND y feeds an OR (bit 0 aside)
+    // Outputs of gates breaking those rules are the swapped wires.
+    fn part2((_, wires): &Self::Input<'_>) -> Result<String, AocError> {
+        let last_z = wires
+            .iter()
+            .map(|wire| wire.c)
+            .filter(|c| c.starts_with('z'))
+            .max()
+            .unwrap_or_default();
+        let feeds = |c: &str, op: &str| {
+            wires
+                .iter()
+                .any(|wire| wire.op == op && (wire.a == c || wire.b == c))
+        };
+
+        Ok(wires
+            .iter()
+            .filter(|wire| {
+                let misplaced_z = wire.op != "XOR"
+                    && wire.c.starts_with('z')
+                    && wire.c != last_z;
+                let broken = match wire.op {
+                    "XOR" if wire.reads_inputs() => {
+                        !wire.reads_first_bit() && !feeds(wire.c, "XOR")
+                    }
+                    "XOR" => !wire.c.starts_with('z'),
+                    "AND" => !wire.reads_first_bit() && !feeds(wire.c, "OR"),
+                    _ => false,
+                };
+                misplaced_z || broken
+            })
+            .map(|wire| wire.c)
+            .sorted()
+            .join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_wire() {
+        assert_eq!(
+            parse_wire("ntg XOR fgs -> mjb"),
+            Ok(("", (("ntg", "XOR", "fgs"), "mjb")))
+        );
+    }
+
+    common::example_tests! {
+        Day24;
+        larger: part1;
+    }
+
+    common::generator_tests! {
+        Day24;
+        max_size: 8;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day24::{Day24, INPUT};
+
+fn main() {
+    common::run::<Day24>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
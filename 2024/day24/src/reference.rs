@@ -0,0 +1,54 @@
+use common::differential::Expected;
+use std::collections::HashMap;
+
+fn signal<'a>(
+    wire: &'a str,
+    gates: &HashMap<&'a str, (&'a str, &'a str, &'a str)>,
+    signals: &mut HashMap<&'a str, usize>,
+) -> usize {
+    if let Some(&signal) = signals.get(wire) {
+        return signal;
+    }
+    let (a, op, b) = gates[wire];
+    let (a, b) = (signal(a, gates, signals), signal(b, gates, signals));
+    let signal = match op {
+        "AND" => a & b,
+        "OR" => a | b,
+        _ => a ^ b,
+    };
+    signals.insert(wire, signal);
+    signal
+}
+
+/// Works out each `z` wire by recursing into the gate that writes it, then
+/// adds up the bits by their number. Part 2 needs the swapped wires, which
+/// a random circuit does not have.
+pub fn expected(input: &str) -> Expected {
+    let (values, circuit) = input.split_once("\n\n").unwrap();
+    let mut signals: HashMap<&str, usize> = values
+        .lines()
+        .map(|line| {
+            let (wire, value) = line.split_once(": ").unwrap();
+            (wire, value.parse().unwrap())
+        })
+        .collect();
+    let gates: HashMap<&str, (&str, &str, &str)> = circuit
+        .lines()
+        .map(|line| {
+            let (gate, c) = line.split_once(" -> ").unwrap();
+            let mut gate = gate.split(' ');
+            let (a, op, b) = (gate.next(), gate.next(), gate.next());
+            (c, (a.unwrap(), op.unwrap(), b.unwrap()))
+        })
+        .collect();
+
+    let number = gates
+        .keys()
+        .filter(|wire| wire.starts_with('z'))
+        .map(|&wire| {
+            let bit: usize = wire[1..].parse().unwrap();
+            signal(wire, &gates, &mut signals) << bit
+        })
+        .sum::<usize>();
+    Expected::part1(number)
+}
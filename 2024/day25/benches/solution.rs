@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day25::Day25);
+
+fn main() {
+    divan::main();
+}
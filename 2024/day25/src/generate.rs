@@ -0,0 +1,25 @@
+use rand::Rng;
+
+/// Up to `size` locks and keys, five pins wide and seven rows tall, with
+/// random pin heights.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let schematics: Vec<String> = (0..rng.gen_range(1..=size))
+        .map(|_| {
+            let lock = rng.gen_bool(0.5);
+            let heights: Vec<usize> =
+                (0..5).map(|_| rng.gen_range(0..=5)).collect();
+            (0..7)
+                .map(|row| {
+                    // Rows counted from the full end
+                    let depth = if lock { row } else { 6 - row };
+                    let pins: String = heights
+                        .iter()
+                        .map(|&height| if depth <= height { '#' } else { '.' })
+                        .collect();
+                    pins + "\n"
+                })
+                .collect()
+        })
+        .collect();
+    schematics.join("\n")
+}
@@ -0,0 +1,127 @@
+use common::{AocError, Matrix, NoAnswer, Solution};
+use tracing::trace;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+trait Height {
+    fn get_columns(&self) -> (&str, Vec<usize>);
+}
+
+impl Height for Matrix<char> {
+    fn get_columns(&self) -> (&str, Vec<usize>) {
+        let mut counts = vec![];
+        for j in 0..self.width {
+            let mut count = 0;
+            for i in 0..self.height {
+                if self.matrix[i][j] == '#' {
+                    count += 1;
+                }
+            }
+            counts.push(count);
+        }
+        if (0..self.width)
+            .filter(|&j| self.matrix[0][j] == '#')
+            .count()
+            == self.width
+        {
+            return ("lock", counts.iter().map(|n| n - 1).collect());
+        }
+        ("key", counts.iter().map(|n| n - 1).collect())
+    }
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<Matrix<char>>;
+    type Part1 = usize;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let schematics = input
+            .split("\n\n")
+            .map(|block| {
+                let schematic =
+                    Matrix::parse(block, |c| "#.".contains(c).then_some(c))?;
+                let full =
+                    |i: usize| schematic.matrix[i].iter().all(|&c| c == '#');
+                if schematic.height < 2
+                    || !(full(0) || full(schematic.height - 1))
+                {
+                    return Err(AocError::at(
+                        block,
+                        "expected a lock or key with a full top or bottom row",
+                    ));
+                }
+                Ok(schematic)
+            })
+            .collect::<Result<Vec<Matrix<char>>, _>>()?;
+        let first = &schematics[0];
+        if let Some(other) = schematics
+            .iter()
+            .find(|m| (m.height, m.width) != (first.height, first.width))
+        {
+            return Err(AocError::new(format!(
+                "schematics differ in size, {}x{} and {}x{}",
+                first.height, first.width, other.height, other.width
+            )));
+        }
+        Ok(schematics)
+    }
+
+    fn part1(matrixes: &Self::Input<'_>) -> Result<usize, AocError> {
+        let overlap_height = matrixes[0].height - 2;
+        let width = matrixes[0].width;
+
+        let (keys, locks) = matrixes.iter().map(|m| m.get_columns()).fold(
+            (vec![], vec![]),
+            |(mut keys, mut locks), (t, columns)| {
+                match t {
+                    "key" => keys.push(columns),
+                    "lock" => locks.push(columns),
+                    _ => unreachable!("a schematic is a key or a lock"),
+                }
+                (keys, locks)
+            },
+        );
+
+        let mut count = 0;
+        for key in keys.iter() {
+            for lock in locks.iter() {
+                trace!("lock {lock:?} and key {key:?}");
+                if (0..width)
+                    .filter(|&i| key[i] + lock[i] <= overlap_height)
+                    .count()
+                    == width
+                {
+                    count += 1;
+                }
+            }
+        }
+        Ok(count)
+    }
+
+    fn part2(_matrixes: &Self::Input<'_>) -> Result<NoAnswer, AocError> {
+        Ok(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day25;
+        example: part1;
+    }
+
+    common::generator_tests! {
+        Day25;
+        max_size: 12;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day25::{Day25, INPUT};
+
+fn main() {
+    common::run::<Day25>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,19 @@
+use common::differential::Expected;
+
+/// Lays every pair of schematics on top of each other and counts the pairs
+/// of a lock and a key with no cell filled in both.
+pub fn expected(input: &str) -> Expected {
+    let schematics: Vec<&str> = input.split("\n\n").collect();
+    let mut fits = 0;
+    for (i, a) in schematics.iter().enumerate() {
+        for b in &schematics[i + 1..] {
+            let lock_and_key = a.starts_with('#') != b.starts_with('#');
+            if lock_and_key
+                && a.chars().zip(b.chars()).all(|(a, b)| a != '#' || b != '#')
+            {
+                fits += 1;
+            }
+        }
+    }
+    Expected::part1(fits)
+}
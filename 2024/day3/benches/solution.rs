@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day3::Day3);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,44 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+const NOISE: [&str; 16] = [
+    "mul[3,7]",
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "mul(32,64]",
+    "?(12,34)",
+    "do",
+    "don't",
+    "undo()",
+    "do_not_",
+    "don't(",
+    "select()",
+    "!@^",
+    "%&",
+    "+",
+    "mul(,5)",
+    ")",
+];
+
+fn mul(rng: &mut impl Rng) -> String {
+    let mut number = || match rng.gen_range(0..10) {
+        0 => rng.gen_range(1000..10000),
+        _ => rng.gen_range(0..1000),
+    };
+    format!("mul({},{})", number(), number())
+}
+
+/// Corrupted memory of `size` pieces: instructions, instructions with one
+/// detail wrong, and noise between them.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let mut memory: String = (0..size)
+        .map(|_| match rng.gen_range(0..6) {
+            0 | 1 => mul(rng),
+            2 => "do()".to_string(),
+            3 => "don't()".to_string(),
+            _ => NOISE.choose(rng).unwrap().to_string(),
+        })
+        .collect();
+    memory.push('\n');
+    memory
+}
@@ -0,0 +1,72 @@
+use common::parse::number;
+use common::{AocError, Solution};
+use regex::Regex;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input<'a> = &'a str;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Result<u32, AocError> {
+        let re =
+            Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").expect("Invalid regex");
+
+        re.captures_iter(input)
+            .map(|cap| Ok(number::<u32>(&cap[1])? * number::<u32>(&cap[2])?))
+            .sum()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Result<u32, AocError> {
+        let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)|don't\(\)|do\(\)")
+            .expect("Invalid regex");
+
+        let (sum, _) = re.captures_iter(input).try_fold(
+            (0, true),
+            |(mut sum, mut active), cap| {
+                match &cap[0] {
+                    "don't()" => active = false,
+                    "do()" => active = true,
+                    _ if active => {
+                        let (a, b): (u32, u32) =
+                            (number(&cap[1])?, number(&cap[2])?);
+                        sum += a * b;
+                    }
+                    _ => {
+                        // do nothing
+                    }
+                }
+                Ok::<_, AocError>((sum, active))
+            },
+        )?;
+        Ok(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day3;
+        example1: part1;
+        example2: part2;
+    }
+
+    common::generator_tests! {
+        Day3;
+        max_size: 30;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day3::{Day3, INPUT};
+
+fn main() {
+    common::run::<Day3>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,35 @@
+use common::differential::Expected;
+
+/// A number of one to three digits at the start of `s`, and what follows.
+fn number(s: &str) -> Option<(u32, &str)> {
+    let len = s.chars().take_while(char::is_ascii_digit).count();
+    match len {
+        1..=3 => Some((s[..len].parse().unwrap(), &s[len..])),
+        _ => None,
+    }
+}
+
+fn mul(s: &str) -> Option<u32> {
+    let (a, rest) = number(s.strip_prefix("mul(")?)?;
+    let (b, rest) = number(rest.strip_prefix(',')?)?;
+    rest.starts_with(')').then_some(a * b)
+}
+
+/// Tries every position of the memory against each instruction.
+pub fn expected(input: &str) -> Expected {
+    let (mut all, mut enabled, mut active) = (0, 0, true);
+    for i in 0..input.len() {
+        let rest = &input[i..];
+        if rest.starts_with("do()") {
+            active = true;
+        } else if rest.starts_with("don't()") {
+            active = false;
+        } else if let Some(product) = mul(rest) {
+            all += product;
+            if active {
+                enabled += product;
+            }
+        }
+    }
+    Expected::both(all, enabled)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day4::Day4);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,16 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// A `size` by `size` word search of the letters of XMAS, with the odd
+/// other letter.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let mut line: String = (0..size)
+                .map(|_| *b"XMASXMASXMASO".choose(rng).unwrap() as char)
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
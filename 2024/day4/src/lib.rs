@@ -0,0 +1,124 @@
+use common::{AocError, Solution};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+trait Xmas {
+    fn exist(&self) -> usize {
+        0
+    }
+}
+
+impl Xmas for Vec<char> {
+    fn exist(&self) -> usize {
+        let s = self.iter().collect::<String>();
+        let rev_s = self.iter().rev().collect::<String>();
+        s.matches("XMAS").count() + rev_s.matches("XMAS").count()
+    }
+}
+
+fn count(matrix: &[Vec<char>]) -> usize {
+    matrix.iter().map(|v| v.exist()).sum()
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input<'a> = Vec<Vec<char>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let n = input.lines().count();
+        if n == 0 {
+            return Err(AocError::new("the word search is empty"));
+        }
+        input
+            .lines()
+            .map(|line| match line.chars().count() {
+                len if len == n => Ok(line.chars().collect()),
+                _ => Err(AocError::at(
+                    line,
+                    format!("the word search must be square, {n} by {n}"),
+                )),
+            })
+            .collect()
+    }
+
+    fn part1(matrix: &Self::Input<'_>) -> Result<usize, AocError> {
+        let n = matrix.len();
+        let vertical_matrix: Vec<Vec<char>> = (0..n)
+            .map(|j| (0..n).map(|i| matrix[i][j]).collect())
+            .collect();
+
+        let diagonal_matrix: Vec<Vec<char>> = (0..n)
+            .map(|d| (0..=d).map(|i| matrix[i][d - i]).collect())
+            .chain((1..n).map(|d| {
+                (0..n - d).map(|i| matrix[d + i][n - 1 - i]).collect()
+            }))
+            .collect();
+
+        let anti_diagonal_matrix: Vec<Vec<char>> = (0..2 * n - 1)
+            .map(|diagonal| {
+                (0..n)
+                    .filter_map(|row| {
+                        let col = diagonal as i32 - row as i32;
+                        if col >= 0 && col < n as i32 {
+                            Some(matrix[n - 1 - row][col as usize])
+                        } else {
+                            None
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(count(matrix)
+            + count(&vertical_matrix)
+            + count(&diagonal_matrix)
+            + count(&anti_diagonal_matrix))
+    }
+
+    fn part2(matrix: &Self::Input<'_>) -> Result<usize, AocError> {
+        let n = matrix.len();
+        let cross = [('M', 'S'), ('S', 'M')];
+        Ok((1..n - 1)
+            .flat_map(|i| (1..n - 1).map(move |j| (i, j)))
+            .map(|(i, j)| {
+                if matrix[i][j] != 'A' {
+                    return false;
+                }
+                if cross
+                    .contains(&(matrix[i - 1][j - 1], matrix[i + 1][j + 1]))
+                    && cross.contains(&(
+                        matrix[i - 1][j + 1],
+                        matrix[i + 1][j - 1],
+                    ))
+                {
+                    return true;
+                }
+                false
+            })
+            .filter(|&b| b)
+            .count())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day4;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day4;
+        max_size: 12;
+    }
+}
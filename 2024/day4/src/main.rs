@@ -0,0 +1,5 @@
+use y2024_day4::{Day4, INPUT};
+
+fn main() {
+    common::run::<Day4>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,37 @@
+use common::differential::Expected;
+
+/// Reads XMAS from every cell in all eight directions, and the two MAS of
+/// an X around every A.
+pub fn expected(input: &str) -> Expected {
+    let grid: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let at = |i: isize, j: isize| -> char {
+        usize::try_from(i)
+            .ok()
+            .zip(usize::try_from(j).ok())
+            .and_then(|(i, j)| grid.get(i)?.get(j).copied())
+            .unwrap_or(' ')
+    };
+    let n = grid.len() as isize;
+    let cells = || (0..n).flat_map(|i| (0..n).map(move |j| (i, j)));
+
+    let mut xmas = 0;
+    for (i, j) in cells() {
+        for di in -1..=1 {
+            for dj in -1..=1 {
+                let word: String =
+                    (0..4).map(|k| at(i + k * di, j + k * dj)).collect();
+                xmas += usize::from(word == "XMAS");
+            }
+        }
+    }
+    let mas = |a: char, b: char| matches!((a, b), ('M', 'S') | ('S', 'M'));
+    let crosses = cells()
+        .filter(|&(i, j)| {
+            at(i, j) == 'A'
+                && mas(at(i - 1, j - 1), at(i + 1, j + 1))
+                && mas(at(i - 1, j + 1), at(i + 1, j - 1))
+        })
+        .count();
+    Expected::both(xmas, crosses)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day5::Day5);
+
+fn main() {
+    divan::main();
+}
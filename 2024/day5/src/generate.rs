@@ -0,0 +1,34 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Ordering rules for every pair of `size + 2` pages, taken from a hidden
+/// order of them, then `size` updates of an odd number of those pages,
+/// some already in order.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let mut pages: Vec<usize> = (10..100).collect();
+    pages.shuffle(rng);
+    pages.truncate(size + 2);
+
+    let mut rules = vec![];
+    for (i, before) in pages.iter().enumerate() {
+        for after in &pages[i + 1..] {
+            rules.push(format!("{before}|{after}\n"));
+        }
+    }
+    rules.shuffle(rng);
+
+    let updates = (0..size).map(|_| {
+        let len = rng.gen_range(0..=pages.len().min(7) / 2) * 2 + 1;
+        let mut update: Vec<usize> =
+            pages.choose_multiple(rng, len).copied().collect();
+        if rng.gen_bool(0.4) {
+            update.sort_by_key(|page| {
+                pages.iter().position(|p| p == page).unwrap()
+            });
+        }
+        let update: Vec<String> =
+            update.iter().map(ToString::to_string).collect();
+        update.join(",") + "\n"
+    });
+    rules.concat() + "\n" + &updates.collect::<String>()
+}
@@ -0,0 +1,129 @@
+use common::parse::{number, split_once};
+use common::{AocError, Solution};
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn get_following_map(
+    first_section: &str,
+) -> Result<HashMap<&str, HashSet<&str>>, AocError> {
+    let mut map: HashMap<&str, HashSet<&str>> = HashMap::new();
+    for line in first_section.lines() {
+        let (before, after) = split_once(line, "|")?;
+        number::<usize>(before)?;
+        number::<usize>(after)?;
+        map.entry(before).or_default().insert(after);
+    }
+    Ok(map)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input<'a> = (HashMap<&'a str, HashSet<&'a str>>, Vec<Vec<&'a str>>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let (first_section, second_section) = split_once(input, "\n\n")?;
+        let updates = second_section
+            .lines()
+            .map(|line| {
+                let pages: Vec<&str> = line.split(',').collect();
+                for page in &pages {
+                    number::<usize>(page)?;
+                }
+                Ok(pages)
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok((get_following_map(first_section)?, updates))
+    }
+
+    fn part1(
+        (following_map, updates): &Self::Input<'_>,
+    ) -> Result<usize, AocError> {
+        updates
+            .iter()
+            .map(|nums| {
+                let mid = nums[nums.len() / 2];
+                let (correct, _) = nums.iter().fold(
+                    (true, HashSet::new()),
+                    |(mut correct, mut befores), num| {
+                        if !correct {
+                            return (correct, befores);
+                        }
+                        if let Some(set) = following_map.get(num) {
+                            if !befores.is_disjoint(set) {
+                                correct = false;
+                                return (correct, befores);
+                            }
+                        }
+                        befores.insert(*num);
+                        (correct, befores)
+                    },
+                );
+                (mid, correct)
+            })
+            .filter_map(|(mid, correct)| {
+                if correct {
+                    return Some(number::<usize>(mid));
+                }
+                None
+            })
+            .try_fold(0usize, |sum, mid: Result<usize, AocError>| {
+                sum.checked_add(mid?).ok_or_else(|| {
+                    AocError::new("the middle page numbers add up past usize")
+                })
+            })
+    }
+
+    fn part2(
+        (following_map, updates): &Self::Input<'_>,
+    ) -> Result<usize, AocError> {
+        updates
+            .iter()
+            .map(|nums| {
+                let mut nums_cloned = nums.clone();
+                nums_cloned.sort_by(|a, b| {
+                    match following_map
+                        .get(*a)
+                        .unwrap_or(&HashSet::new())
+                        .contains(b)
+                    {
+                        true => Ordering::Less,
+                        false => Ordering::Greater,
+                    }
+                });
+                if nums_cloned == *nums {
+                    return Ok(0);
+                }
+                number::<usize>(nums_cloned[nums_cloned.len() / 2])
+            })
+            .try_fold(0usize, |sum, mid: Result<usize, AocError>| {
+                sum.checked_add(mid?).ok_or_else(|| {
+                    AocError::new("the middle page numbers add up past usize")
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day5;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day5;
+        max_size: 12;
+    }
+}
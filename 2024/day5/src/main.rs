@@ -0,0 +1,5 @@
+use y2024_day5::{Day5, INPUT};
+
+fn main() {
+    common::run::<Day5>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,37 @@
+use common::differential::Expected;
+
+/// Checks every pair of pages of an update against the rules, and puts a
+/// wrong one in order by picking, again and again, a page no other
+/// remaining page has to come before.
+pub fn expected(input: &str) -> Expected {
+    let (rules, updates) = input.split_once("\n\n").unwrap();
+    let rules: Vec<(&str, &str)> = rules
+        .lines()
+        .map(|line| line.split_once('|').unwrap())
+        .collect();
+    let before = |a: &str, b: &str| rules.contains(&(a, b));
+
+    let (mut correct, mut fixed) = (0, 0);
+    for update in updates.lines() {
+        let mut pages: Vec<&str> = update.split(',').collect();
+        let in_order = (0..pages.len()).all(|i| {
+            (i + 1..pages.len()).all(|j| !before(pages[j], pages[i]))
+        });
+        let middle = |pages: &[&str]| -> usize {
+            pages[pages.len() / 2].parse().unwrap()
+        };
+        if in_order {
+            correct += middle(&pages);
+            continue;
+        }
+        let mut sorted = vec![];
+        while !pages.is_empty() {
+            let first = (0..pages.len())
+                .find(|&i| pages.iter().all(|other| !before(other, pages[i])))
+                .unwrap();
+            sorted.push(pages.remove(first));
+        }
+        fixed += middle(&sorted);
+    }
+    Expected::both(correct, fixed)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day6::Day6);
+
+fn main() {
+    divan::main();
+}
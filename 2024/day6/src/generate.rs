@@ -0,0 +1,49 @@
+use rand::Rng;
+use std::collections::HashSet;
+
+/// Whether the guard walks out of `grid` rather than around in circles.
+fn leaves(grid: &[Vec<u8>], start: (usize, usize)) -> bool {
+    let (mut pos, mut dir) = (start, (-1, 0));
+    let mut seen = HashSet::new();
+    while seen.insert((pos, dir)) {
+        let next = (
+            pos.0.checked_add_signed(dir.0),
+            pos.1.checked_add_signed(dir.1),
+        );
+        let (Some(i), Some(j)) = next else {
+            return true;
+        };
+        match grid.get(i).and_then(|row| row.get(j)) {
+            None => return true,
+            Some(b'#') => dir = (dir.1, -dir.0),
+            Some(_) => pos = (i, j),
+        }
+    }
+    false
+}
+
+/// A lab of up to `size + 1` by `size + 1` tiles with scattered
+/// obstructions and a guard who eventually leaves it, as the puzzle
+/// promises.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    loop {
+        let (m, n) =
+            (rng.gen_range(1..=size + 1), rng.gen_range(1..=size + 1));
+        let density = rng.gen_range(0.05..0.35);
+        let mut grid: Vec<Vec<u8>> = (0..m)
+            .map(|_| {
+                (0..n)
+                    .map(|_| if rng.gen_bool(density) { b'#' } else { b'.' })
+                    .collect()
+            })
+            .collect();
+        let start = (rng.gen_range(0..m), rng.gen_range(0..n));
+        grid[start.0][start.1] = b'^';
+        if leaves(&grid, start) {
+            return grid
+                .into_iter()
+                .map(|row| String::from_utf8(row).unwrap() + "\n")
+                .collect();
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use common::stepper::{Snapshot, StepThrough, Tape};
+use common::viz::{Cell, Frame, Recording, Visualize, RED, YELLOW};
+use common::{AocError, Matrix, Solution};
+use std::collections::HashSet;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn is_loop(
+    matrix: Vec<Vec<char>>,
+    mut pos_i: usize,
+    mut pos_j: usize,
+    mut di: isize,
+    mut dj: isize,
+) -> bool {
+    let (m, n) = (matrix.len(), matrix[0].len());
+    let mut visited = HashSet::new();
+    while let (Some(i), Some(j)) =
+        (pos_i.checked_add_signed(di), pos_j.checked_add_signed(dj))
+    {
+        if visited.contains(&(pos_i, pos_j, di, dj)) {
+            return true;
+        }
+        if i == m || j == n {
+            return false;
+        }
+        visited.insert((pos_i, pos_j, di, dj));
+        match matrix[i][j] {
+            // turn right
+            '#' => (di, dj) = (dj, -di),
+            _ => (pos_i, pos_j) = (i, j),
+        }
+    }
+    false
+}
+
+pub struct Lab {
+    matrix: Vec<Vec<char>>,
+    start: (usize, usize),
+}
+
+/// Walks the guard out of the lab, calling `on_step` with the tiles visited
+/// so far and the guard's position after every move or turn. Fails if the
+/// guard never leaves.
+fn walk(
+    lab: &Lab,
+    mut on_step: impl FnMut(&HashSet<(usize, usize)>, (usize, usize)),
+) -> Result<HashSet<(usize, usize)>, AocError> {
+    let matrix = &lab.matrix;
+    let (m, n) = (matrix.len(), matrix[0].len());
+    let (mut di, mut dj): (isize, isize) = (-1, 0);
+    let mut visited = HashSet::new();
+
+    let (mut pos_i, mut pos_j): (usize, usize) = lab.start;
+    visited.insert((pos_i, pos_j));
+    // More moves and turns than there are positions and headings means the
+    // guard is back where they were, facing the same way
+    let mut steps = 0;
+    while let (Some(i), Some(j)) =
+        (pos_i.checked_add_signed(di), pos_j.checked_add_signed(dj))
+    {
+        if i == m || j == n {
+            break;
+        }
+        steps += 1;
+        if steps > 4 * m * n {
+            return Err(AocError::new("the guard walks in a loop"));
+        }
+        match matrix[i][j] {
+            // turn right
+            '#' => (di, dj) = (dj, -di),
+            _ => {
+                (pos_i, pos_j) = (i, j);
+                visited.insert((pos_i, pos_j));
+            }
+        }
+        on_step(&visited, (pos_i, pos_j));
+    }
+    Ok(visited)
+}
+
+fn lab_frame(
+    lab: &Lab,
+    visited: &HashSet<(usize, usize)>,
+    guard: (usize, usize),
+) -> Frame {
+    let (m, n) = (lab.matrix.len(), lab.matrix[0].len());
+    Frame::from_fn(m, n, |i, j| match lab.matrix[i][j] {
+        _ if (i, j) == guard => Cell::colored('^', RED),
+        _ if visited.contains(&(i, j)) => Cell::colored('X', YELLOW),
+        '#' => Cell::from('#'),
+        _ => Cell::from('.'),
+    })
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input<'a> = Lab;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let matrix = Matrix::parse(input, |c| "#.^".contains(c).then_some(c))?;
+        let start = matrix
+            .find(&'^')
+            .ok_or_else(|| AocError::new("there is no guard ^ in the lab"))?;
+        Ok(Lab {
+            matrix: matrix.matrix,
+            start,
+        })
+    }
+
+    fn part1(lab: &Self::Input<'_>) -> Result<usize, AocError> {
+        Ok(walk(lab, |_, _| {})?.len())
+    }
+
+    fn part2(lab: &Self::Input<'_>) -> Result<usize, AocError> {
+        let (start_i, start_j) = lab.start;
+        let path = walk(lab, |_, _| {})?;
+        let progress = common::progress::bar(path.len(), "day 6 obstacles");
+        progress
+            .wrap_iter(path.into_iter())
+            // Not at the start, where the guard would notice it
+            .filter(|&obstruction| obstruction != lab.start)
+            .try_fold(0, |loops, (obs_i, obs_j)| {
+                common::budget::check()?;
+                let mut matrix_clone = lab.matrix.clone();
+                matrix_clone[obs_i][obs_j] = '#';
+                let found = is_loop(matrix_clone, start_i, start_j, -1, 0);
+                Ok(loops + usize::from(found))
+            })
+    }
+}
+
+impl Visualize for Day6 {
+    fn visualize(
+        lab: &Self::Input<'_>,
+        recording: &mut Recording,
+    ) -> Result<(), AocError> {
+        let mut guard = lab.start;
+        let visited = walk(lab, |visited, position| {
+            guard = position;
+            recording.record(|| lab_frame(lab, visited, position));
+        })?;
+        recording.finish(lab_frame(lab, &visited, guard));
+        Ok(())
+    }
+}
+
+impl StepThrough for Day6 {
+    /// The guard's walk, one move or turn per step, for either part.
+    fn step_through(
+        lab: &Self::Input<'_>,
+        _part: u8,
+        tape: &mut Tape,
+    ) -> Result<(), AocError> {
+        let start = HashSet::from([lab.start]);
+        tape.record(
+            Snapshot::new("start", lab_frame(lab, &start, lab.start))
+                .with_focus(lab.start),
+        );
+        let mut previous = lab.start;
+        walk(lab, |visited, guard| {
+            let label = match guard == previous {
+                true => "turn right".to_string(),
+                false => format!("step to {guard:?}"),
+            };
+            let panel = vec![format!("{} tiles visited", visited.len())];
+            tape.record(
+                Snapshot::new(label, lab_frame(lab, visited, guard))
+                    .with_panel(panel)
+                    .with_focus(guard),
+            );
+            previous = guard;
+        })?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    common::example_tests! {
+        Day6;
+        example: part1, part2;
+    }
+
+    #[test]
+    fn test_guard_in_a_loop() {
+        let lab = Day6::parse(indoc! {"
+            .#..
+            ...#
+            #^..
+            ..#.
+        "})
+        .unwrap();
+        let err = Day6::part1(&lab).unwrap_err();
+        assert_eq!(err.message(), "the guard walks in a loop");
+    }
+
+    common::generator_tests! {
+        Day6;
+        max_size: 10;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day6::{Day6, INPUT};
+
+fn main() {
+    common::run::<Day6>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,50 @@
+use common::differential::Expected;
+use std::collections::HashSet;
+
+/// The tiles the guard visits, or `None` if they walk in a loop. The walk
+/// is cut off after more steps than there are positions and headings.
+fn walk(grid: &[Vec<char>], start: (isize, isize)) -> Option<usize> {
+    let (m, n) = (grid.len() as isize, grid[0].len() as isize);
+    let (mut pos, mut dir) = (start, (-1, 0));
+    let mut visited = HashSet::from([pos]);
+    for _ in 0..=4 * m * n {
+        let (i, j) = (pos.0 + dir.0, pos.1 + dir.1);
+        if !(0..m).contains(&i) || !(0..n).contains(&j) {
+            return Some(visited.len());
+        }
+        if grid[i as usize][j as usize] == '#' {
+            dir = (dir.1, -dir.0);
+        } else {
+            pos = (i, j);
+            visited.insert(pos);
+        }
+    }
+    None
+}
+
+/// Walks the guard, then walks them again with an obstruction on each
+/// empty tile in turn.
+pub fn expected(input: &str) -> Expected {
+    let mut grid: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let (m, n) = (grid.len(), grid[0].len());
+    let start = (0..m)
+        .flat_map(|i| (0..n).map(move |j| (i, j)))
+        .find(|&(i, j)| grid[i][j] == '^')
+        .unwrap();
+    let from = (start.0 as isize, start.1 as isize);
+
+    let visited = walk(&grid, from).unwrap();
+    let mut loops = 0;
+    for i in 0..m {
+        for j in 0..n {
+            if grid[i][j] != '.' {
+                continue;
+            }
+            grid[i][j] = '#';
+            loops += usize::from(walk(&grid, from).is_none());
+            grid[i][j] = '.';
+        }
+    }
+    Expected::both(visited, loops)
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day7::Day7);
+
+fn main() {
+    divan::main();
+}
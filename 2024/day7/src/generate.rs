@@ -0,0 +1,26 @@
+use rand::Rng;
+
+/// `size` equations of one to six numbers. Most test values come from
+/// combining the numbers with random operators, the rest are made up.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            let len = rng.gen_range(1..=6);
+            let nums: Vec<usize> =
+                (0..len).map(|_| rng.gen_range(1..=30)).collect();
+            let value = match rng.gen_bool(0.7) {
+                true => nums[1..].iter().fold(nums[0], |acc, &n| {
+                    match rng.gen_range(0..3) {
+                        0 => acc + n,
+                        1 => acc * n,
+                        _ => format!("{acc}{n}").parse().unwrap(),
+                    }
+                }),
+                false => rng.gen_range(1..=1000),
+            };
+            let nums: Vec<String> =
+                nums.iter().map(ToString::to_string).collect();
+            format!("{value}: {}\n", nums.join(" "))
+        })
+        .collect()
+}
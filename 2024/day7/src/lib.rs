@@ -0,0 +1,114 @@
+use common::parse::{number, split_once};
+use common::{AocError, Solution};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input<'a> = Vec<(usize, Vec<usize>)>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        input
+            .lines()
+            .map(|line| {
+                let (value, nums) = split_once(line, ": ")?;
+                let nums = nums
+                    .split_whitespace()
+                    .map(number)
+                    .collect::<Result<_, _>>()?;
+                Ok((number(value)?, nums))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input<'_>) -> Result<usize, AocError> {
+        equations
+            .iter()
+            .map(|(value, nums)| {
+                // Each number multiplies the values by the number of operators
+                let evals =
+                    nums.iter().try_fold(vec![], |acc: Vec<usize>, &n| {
+                        common::budget::check()?;
+                        Ok::<_, AocError>(if acc.is_empty() {
+                            vec![n]
+                        } else {
+                            acc.iter()
+                                .flat_map(|&prev| {
+                                    [prev.checked_mul(n), prev.checked_add(n)]
+                                })
+                                .flatten()
+                                .collect()
+                        })
+                    })?;
+                if evals.contains(value) {
+                    return Ok(*value);
+                }
+                Ok(0)
+            })
+            .try_fold(0usize, |sum, value: Result<usize, AocError>| {
+                sum.checked_add(value?).ok_or_else(|| {
+                    AocError::new("the calibration results add up past usize")
+                })
+            })
+    }
+
+    fn part2(equations: &Self::Input<'_>) -> Result<usize, AocError> {
+        equations
+            .iter()
+            .map(|(value, nums)| {
+                // Each number multiplies the values by the number of operators
+                let evals =
+                    nums.iter().try_fold(vec![], |acc: Vec<usize>, &n| {
+                        common::budget::check()?;
+                        Ok::<_, AocError>(if acc.is_empty() {
+                            vec![n]
+                        } else {
+                            acc.iter()
+                                .flat_map(|&prev| {
+                                    [
+                                        prev.checked_mul(n),
+                                        prev.checked_add(n),
+                                        (prev.to_string() + &n.to_string())
+                                            .parse()
+                                            .ok(),
+                                    ]
+                                })
+                                .flatten()
+                                .collect()
+                        })
+                    })?;
+                if evals.contains(value) {
+                    return Ok(*value);
+                }
+                Ok(0)
+            })
+            .try_fold(0usize, |sum, value: Result<usize, AocError>| {
+                sum.checked_add(value?).ok_or_else(|| {
+                    AocError::new("the calibration results add up past usize")
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day7;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day7;
+        max_size: 10;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day7::{Day7, INPUT};
+
+fn main() {
+    common::run::<Day7>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,38 @@
+use common::differential::Expected;
+
+/// Tries every choice of operators, counting through them as the digits of
+/// a number in base `operators`.
+fn solvable(value: u64, nums: &[u64], operators: u32) -> bool {
+    let choices = operators.pow(nums.len() as u32 - 1);
+    (0..choices).any(|mut choice| {
+        let result = nums[1..].iter().fold(nums[0], |acc, &n| {
+            let operator = choice % operators;
+            choice /= operators;
+            match operator {
+                0 => acc + n,
+                1 => acc * n,
+                _ => acc * 10u64.pow(n.to_string().len() as u32) + n,
+            }
+        });
+        result == value
+    })
+}
+
+pub fn expected(input: &str) -> Expected {
+    let equations: Vec<(u64, Vec<u64>)> = input
+        .lines()
+        .map(|line| {
+            let (value, nums) = line.split_once(": ").unwrap();
+            let nums = nums.split(' ').map(|n| n.parse().unwrap()).collect();
+            (value.parse().unwrap(), nums)
+        })
+        .collect();
+    let total = |operators| -> u64 {
+        equations
+            .iter()
+            .filter(|(value, nums)| solvable(*value, nums, operators))
+            .map(|(value, _)| value)
+            .sum()
+    };
+    Expected::both(total(2), total(3))
+}
@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day8::Day8);
+
+fn main() {
+    divan::main();
+}
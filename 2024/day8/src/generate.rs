@@ -0,0 +1,22 @@
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// A map of up to `size + 1` by `size + 1` with antennas of a few
+/// frequencies, so most frequencies have several antennas.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let (m, n) = (rng.gen_range(1..=size + 1), rng.gen_range(1..=size + 1));
+    let frequencies = &['a', 'A', '0', 'z'][..rng.gen_range(1..=4)];
+    let density = rng.gen_range(0.05..0.3);
+    (0..m)
+        .map(|_| {
+            let mut line: String = (0..n)
+                .map(|_| match rng.gen_bool(density) {
+                    true => *frequencies.choose(rng).unwrap(),
+                    false => '.',
+                })
+                .collect();
+            line.push('\n');
+            line
+        })
+        .collect()
+}
@@ -0,0 +1,147 @@
+use common::{AocError, Matrix, Solution};
+use itertools::iproduct;
+use std::collections::{HashMap, HashSet};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+fn mirror_point(
+    point: (usize, usize),
+    reference: (usize, usize),
+) -> Option<(usize, usize)> {
+    let (x, y) = point;
+    let (rx, ry) = reference;
+
+    if 2 * rx >= x && 2 * ry >= y {
+        return Some((2 * rx - x, 2 * ry - y));
+    }
+    None
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    match b {
+        0 => a,
+        _ => gcd(b, a % b),
+    }
+}
+
+fn mirror_point_within_grid(
+    point: (usize, usize),
+    reference: (usize, usize),
+    grid: (usize, usize),
+) -> Vec<(usize, usize)> {
+    let (x, y) = point;
+    let (rx, ry) = reference;
+    let (m, n) = grid;
+    let mut points = vec![];
+
+    if let (Some(dx), Some(dy)) = (
+        (x as isize).checked_sub(rx as isize),
+        (y as isize).checked_sub(ry as isize),
+    ) {
+        // Step to every tile on the line, not just every multiple of the
+        // distance between the antennas
+        let divisor = gcd(dx.unsigned_abs(), dy.unsigned_abs()) as isize;
+        let (dx, dy) = (dx / divisor, dy / divisor);
+        let mut current_rx = rx as isize;
+        let mut current_ry = ry as isize;
+
+        while let (Ok(new_x), Ok(new_y)) =
+            ((current_rx + dx).try_into(), (current_ry + dy).try_into())
+        {
+            if new_x >= m || new_y >= n {
+                break;
+            }
+            points.push((new_x, new_y));
+            current_rx += dx;
+            current_ry += dy;
+        }
+    }
+
+    points
+}
+
+pub struct Antennas {
+    antennas_map: HashMap<char, Vec<(usize, usize)>>,
+    grid: (usize, usize),
+}
+
+impl Antennas {
+    fn pairs(
+        &self,
+    ) -> impl Iterator<Item = (&(usize, usize), &(usize, usize))> {
+        self.antennas_map.values().flat_map(|pairs| {
+            iproduct!(pairs.iter(), pairs.iter()).filter(|&(x, y)| x != y)
+        })
+    }
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input<'a> = Antennas;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        let matrix = Matrix::parse(input, |c| {
+            (c == '.' || c.is_ascii_alphanumeric()).then_some(c)
+        })?;
+        let (m, n) = (matrix.height, matrix.width);
+        let mut antennas_map: HashMap<char, Vec<(usize, usize)>> =
+            HashMap::new();
+        for (i, j) in (0..m).flat_map(|i| (0..n).map(move |j| (i, j))) {
+            if matrix[(i, j)] != '.' {
+                antennas_map.entry(matrix[(i, j)]).or_default().push((i, j));
+            }
+        }
+        Ok(Antennas {
+            antennas_map,
+            grid: (m, n),
+        })
+    }
+
+    fn part1(antennas: &Self::Input<'_>) -> Result<usize, AocError> {
+        let (m, n) = antennas.grid;
+        let mut antinodes = HashSet::new();
+        for (x, y) in antennas.pairs() {
+            if let Some((mirror_x, mirror_y)) = mirror_point(*x, *y) {
+                if mirror_x < m && mirror_y < n {
+                    antinodes.insert((mirror_x, mirror_y));
+                }
+            }
+        }
+        Ok(antinodes.len())
+    }
+
+    fn part2(antennas: &Self::Input<'_>) -> Result<usize, AocError> {
+        let mut antinodes_updated = HashSet::new();
+        for (x, y) in antennas.pairs() {
+            antinodes_updated.extend(mirror_point_within_grid(
+                *x,
+                *y,
+                antennas.grid,
+            ));
+        }
+        Ok(antinodes_updated.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        Day8;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day8;
+        max_size: 10;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day8::{Day8, INPUT};
+
+fn main() {
+    common::run::<Day8>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,38 @@
+use common::differential::Expected;
+
+/// Checks every tile against every pair of antennas of one frequency: for
+/// part 1 whether it is twice as far from one as from the other, for part
+/// 2 whether it is on their line at all.
+pub fn expected(input: &str) -> Expected {
+    let grid: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let tiles: Vec<(i64, i64)> = (0..grid.len())
+        .flat_map(|i| (0..grid[0].len()).map(move |j| (i as i64, j as i64)))
+        .collect();
+    let frequency = |(i, j): (i64, i64)| grid[i as usize][j as usize];
+    let pairs: Vec<((i64, i64), (i64, i64))> = tiles
+        .iter()
+        .flat_map(|&a| tiles.iter().map(move |&b| (a, b)))
+        .filter(|&(a, b)| {
+            a != b && frequency(a) != '.' && frequency(a) == frequency(b)
+        })
+        .collect();
+
+    let antinodes = tiles
+        .iter()
+        .filter(|&&(i, j)| {
+            pairs
+                .iter()
+                .any(|&(a, b)| (i, j) == (2 * b.0 - a.0, 2 * b.1 - a.1))
+        })
+        .count();
+    let in_line = tiles
+        .iter()
+        .filter(|&&(i, j)| {
+            pairs.iter().any(|&(a, b)| {
+                (b.0 - a.0) * (j - a.1) == (b.1 - a.1) * (i - a.0)
+            })
+        })
+        .count();
+    Expected::both(antinodes, in_line)
+}
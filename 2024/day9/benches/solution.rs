@@ -0,0 +1,5 @@
+common::solution_benches!(y2024_day9::Day9);
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,15 @@
+use rand::Rng;
+
+/// A disk map of `size` files of one to nine blocks, with up to nine free
+/// blocks after each.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    let mut map = String::new();
+    for i in 0..size {
+        map.push(char::from(b'0' + rng.gen_range(1..=9)));
+        if i + 1 < size || rng.gen_bool(0.5) {
+            map.push(char::from(b'0' + rng.gen_range(0..=9)));
+        }
+    }
+    map.push('\n');
+    map
+}
@@ -0,0 +1,163 @@
+use common::parse::number;
+use common::{AocError, Solution};
+use itertools::Itertools;
+use sorted_vec::SortedVec;
+use std::cmp::Ordering;
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+#[derive(Debug, Clone)]
+pub struct File {
+    address: usize,
+    size: usize,
+    id: usize,
+}
+
+#[derive(Debug, Clone, Eq)]
+pub struct Space {
+    address: usize,
+    size: usize,
+}
+
+impl Ord for Space {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.address.cmp(&self.address)
+    }
+}
+
+impl PartialOrd for Space {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Space {
+    fn eq(&self, other: &Self) -> bool {
+        self.address == other.address && self.size == other.size
+    }
+}
+
+type DiskMap = (Vec<String>, Vec<File>, SortedVec<Space>);
+
+fn get_disk_map(input: &str) -> Result<DiskMap, AocError> {
+    let input = input.trim();
+    if let Some(i) = input.find(|c: char| !c.is_ascii_digit()) {
+        return Err(AocError::at(&input[i..], "expected a digit"));
+    }
+    let mut address = 0;
+    Ok(input.bytes().chunks(2).into_iter().enumerate().fold(
+        (vec![], vec![], SortedVec::new()),
+        |(mut s, mut files, mut spaces), (i, chunk)| {
+            let chunk: Vec<usize> =
+                chunk.map(|b| (b - b'0') as usize).collect();
+            let file_size = chunk[0];
+            s.extend(vec![i.to_string(); file_size]);
+            files.push(File {
+                address,
+                size: file_size,
+                id: i,
+            });
+            address += file_size;
+            if let Some(&space_size) = chunk.get(1) {
+                s.extend(vec![".".to_string(); space_size]);
+                spaces.push(Space {
+                    address,
+                    size: space_size,
+                });
+                address += space_size;
+            }
+            (s, files, spaces)
+        },
+    ))
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input<'a> = DiskMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        get_disk_map(input)
+    }
+
+    fn part1((disk_map, _, _): &Self::Input<'_>) -> Result<usize, AocError> {
+        let mut disk_map = disk_map.clone();
+        let (mut i, mut j) = (0, disk_map.len().saturating_sub(1));
+        while i < j {
+            if disk_map[i] != "." {
+                i += 1;
+                continue;
+            }
+            while j > i && disk_map[j] == "." {
+                j -= 1;
+            }
+            disk_map.swap(i, j);
+        }
+        disk_map
+            .iter()
+            .filter(|&n| *n != ".")
+            .enumerate()
+            .map(|(i, n)| Ok(i * number::<usize>(n)?))
+            .sum()
+    }
+
+    fn part2((_, files, spaces): &Self::Input<'_>) -> Result<usize, AocError> {
+        let (mut files, mut spaces) = (files.clone(), spaces.clone());
+
+        while let Some(mut space) = spaces.pop() {
+            for file in files.iter_mut().rev() {
+                if file.size <= space.size && file.address > space.address {
+                    spaces.push(Space {
+                        address: file.address,
+                        size: file.size,
+                    });
+                    file.address = space.address;
+                    space.address += file.size;
+                    space.size -= file.size;
+                }
+            }
+        }
+        Ok(files
+            .iter()
+            .map(|file| {
+                (file.address..file.address + file.size)
+                    .map(|n| n * file.id)
+                    .sum::<usize>()
+            })
+            .sum())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_disk_map() {
+        assert_eq!(
+            get_disk_map("12345").unwrap().0.join(""),
+            "0..111....22222".to_string()
+        );
+        assert_eq!(
+            get_disk_map("2333133121414131402").unwrap().0.join(""),
+            "00...111...2...333.44.5555.6666.777.888899".to_string()
+        );
+    }
+
+    common::example_tests! {
+        Day9;
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        Day9;
+        max_size: 12;
+    }
+}
@@ -0,0 +1,5 @@
+use y2024_day9::{Day9, INPUT};
+
+fn main() {
+    common::run::<Day9>(env!("CARGO_MANIFEST_DIR"), INPUT);
+}
@@ -0,0 +1,44 @@
+use common::differential::Expected;
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(i, id)| i * id.unwrap_or(0))
+        .sum()
+}
+
+/// Lays out every block, then moves blocks, and whole files, one at a time
+/// as the puzzle describes.
+pub fn expected(input: &str) -> Expected {
+    let mut disk: Vec<Option<usize>> = vec![];
+    for (i, digit) in input.trim().bytes().enumerate() {
+        let id = (i % 2 == 0).then_some(i / 2);
+        disk.extend(vec![id; (digit - b'0') as usize]);
+    }
+
+    let mut blocks = disk.clone();
+    loop {
+        let free = blocks.iter().position(Option::is_none);
+        let last = blocks.iter().rposition(Option::is_some);
+        match (free, last) {
+            (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+            _ => break,
+        }
+    }
+
+    let mut files = disk;
+    let max_id = files.iter().flatten().max().copied().unwrap_or(0);
+    for id in (0..=max_id).rev() {
+        let start = files.iter().position(|&b| b == Some(id)).unwrap();
+        let len = files.iter().filter(|&&b| b == Some(id)).count();
+        let free = (0..start)
+            .find(|&i| files[i..i + len].iter().all(Option::is_none));
+        if let Some(free) = free {
+            for k in 0..len {
+                files.swap(free + k, start + k);
+            }
+        }
+    }
+    Expected::both(checksum(&blocks), checksum(&files))
+}
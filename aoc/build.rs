@@ -0,0 +1,62 @@
+#[path = "src/source.rs"]
+mod source;
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Every day crate in `root`, as its year, day and directory.
+fn day_crates(root: &Path) -> io::Result<Vec<(u16, u8, PathBuf)>> {
+    let mut days = vec![];
+    for entry in fs::read_dir(root)? {
+        let year_dir = entry?.path();
+        let Some(year) = year_dir
+            .file_name()
+            .and_then(|name| name.to_str()?.parse::<u16>().ok())
+        else {
+            continue;
+        };
+        for entry in fs::read_dir(&year_dir)? {
+            let dir = entry?.path();
+            let day = dir
+                .file_name()
+                .and_then(|name| name.to_str()?.strip_prefix("day"))
+                .and_then(|day| day.parse::<u8>().ok());
+            if let Some(day) = day.filter(|_| dir.join("Cargo.toml").is_file())
+            {
+                days.push((year, day, dir));
+            }
+        }
+    }
+    Ok(days)
+}
+
+/// `YEAR/DAY=HASH` for every day crate, with the hash of its sources and
+/// those of `common` and `aoc`, marking each file to rebuild on.
+fn source_hashes(root: &Path) -> io::Result<Vec<String>> {
+    let mut hashes = vec![];
+    for (year, day, dir) in day_crates(root)? {
+        let crates = [dir, root.join("common"), root.join("aoc")];
+        let files = source::files(root, &crates)?;
+        for file in &files {
+            println!("cargo:rerun-if-changed={}", file.display());
+        }
+        let hash = source::hash(root, &files)?;
+        hashes.push(format!("{year}/{day}={hash}"));
+    }
+    Ok(hashes)
+}
+
+/// Hashes the sources of each day when `aoc` is built, for the result
+/// cache to key runs by the source the binary was built from.
+fn main() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace");
+    let hashes = source_hashes(root).unwrap_or_else(|err| {
+        // Without the hashes nothing is cached
+        println!("cargo:warning=cannot hash the sources: {err}");
+        vec![]
+    });
+    println!("cargo:rustc-env=AOC_SOURCE_SHA256={}", hashes.join(" "));
+}
@@ -0,0 +1,238 @@
+use crate::runner::{Part, Run};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The input every day is checked against, even without an answers file.
+pub const DEFAULT_INPUT: &str = "src/input.txt";
+
+const HEADER: &str =
+    "# Expected answers per input file, checked by `aoc verify`\n\n";
+
+/// Known answers of one input, as the solver prints them.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part1: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: String) {
+        match part {
+            Part::One => self.part1 = Some(answer),
+            Part::Two => self.part2 = Some(answer),
+        }
+    }
+}
+
+/// Contents of `YEAR/dayN/answers.toml`: expected answers keyed by the input
+/// file, relative to the day directory.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers(BTreeMap<String, Expected>);
+
+impl Answers {
+    pub fn path(day_dir: &Path) -> PathBuf {
+        day_dir.join("answers.toml")
+    }
+
+    /// Reads the answers file of a day, which may not exist yet.
+    pub fn load(day_dir: &Path) -> Result<Self, String> {
+        let path = Self::path(day_dir);
+        match fs::read_to_string(&path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                Ok(Answers::default())
+            }
+            Err(err) => Err(format!("{}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, day_dir: &Path) -> Result<(), String> {
+        let path = Self::path(day_dir);
+        let content = toml::to_string(self).map_err(|err| format!("{err}"))?;
+        fs::write(&path, format!("{HEADER}{content}"))
+            .map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    /// Input files to check: the recorded ones plus the default input.
+    pub fn inputs(&self) -> Vec<String> {
+        let mut inputs: Vec<String> = self.0.keys().cloned().collect();
+        if !self.0.contains_key(DEFAULT_INPUT) {
+            inputs.insert(0, DEFAULT_INPUT.to_string());
+        }
+        inputs
+    }
+
+    pub fn get(&self, input: &str) -> Option<&Expected> {
+        self.0.get(input)
+    }
+
+    pub fn entry(&mut self, input: &str) -> &mut Expected {
+        self.0.entry(input.to_string()).or_default()
+    }
+}
+
+/// Outcome of checking one part against its recorded answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The answer is known but the solver no longer produces one.
+    Regression {
+        expected: String,
+        error: String,
+    },
+    /// The solver answered but nothing is recorded to compare with.
+    Missing {
+        actual: String,
+    },
+    /// Nothing is recorded and the solver failed anyway.
+    Failed {
+        error: String,
+    },
+}
+
+impl Verdict {
+    pub fn check(
+        expected: Option<&str>,
+        outcome: &Result<Run, String>,
+    ) -> Self {
+        match (expected, outcome) {
+            (Some(expected), Ok(run)) if expected == run.answer => {
+                Verdict::Pass
+            }
+            (Some(expected), Ok(run)) => Verdict::Mismatch {
+                expected: expected.to_string(),
+                actual: run.answer.clone(),
+            },
+            (Some(expected), Err(error)) => Verdict::Regression {
+                expected: expected.to_string(),
+                error: error.clone(),
+            },
+            (None, Ok(run)) => Verdict::Missing {
+                actual: run.answer.clone(),
+            },
+            (None, Err(error)) => Verdict::Failed {
+                error: error.clone(),
+            },
+        }
+    }
+}
+
+impl Verdict {
+    /// One word for the verdict, for tables.
+    pub fn label(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "ok",
+            Verdict::Mismatch { .. } => "MISMATCH",
+            Verdict::Regression { .. } => "REGRESSION",
+            Verdict::Missing { .. } => "missing",
+            Verdict::Failed { .. } => "FAILED",
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Verdict::Mismatch { .. }
+                | Verdict::Regression { .. }
+                | Verdict::Failed { .. }
+        )
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "ok"),
+            Verdict::Mismatch { expected, actual } => {
+                write!(f, "MISMATCH expected {expected}, got {actual}")
+            }
+            Verdict::Regression { expected, error } => {
+                write!(f, "REGRESSION expected {expected}, failed: {error}")
+            }
+            Verdict::Missing { actual } => {
+                write!(f, "missing, got {actual}")
+            }
+            Verdict::Failed { error } => write!(f, "FAILED {error}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_answers_toml() {
+        let content = indoc! {r#"
+            ["src/input.txt"]
+            part1 = "936063"
+            part2 = "23150395"
+
+            ["examples/small.txt"]
+            part1 = "11"
+        "#};
+        let mut answers: Answers = toml::from_str(content).unwrap();
+        assert_eq!(answers.inputs(), ["examples/small.txt", "src/input.txt"]);
+        let small = answers.get("examples/small.txt").unwrap();
+        assert_eq!(small.get(Part::One), Some("11"));
+        assert_eq!(small.get(Part::Two), None);
+
+        answers
+            .entry("examples/small.txt")
+            .set(Part::Two, "31".into());
+        let saved = toml::to_string(&answers).unwrap();
+        assert_eq!(toml::from_str::<Answers>(&saved).unwrap(), answers);
+        assert_eq!(Answers::default().inputs(), [DEFAULT_INPUT]);
+    }
+
+    #[test]
+    fn test_verdict() {
+        let run = |answer: &str| {
+            Ok(Run {
+                answer: answer.to_string(),
+                parse: Duration::ZERO,
+                solve: Duration::ZERO,
+                memory: None,
+            })
+        };
+        let failed = || Err("disco!".to_string());
+        assert_eq!(Verdict::check(Some("42"), &run("42")), Verdict::Pass);
+        assert!(matches!(
+            Verdict::check(Some("42"), &run("41")),
+            Verdict::Mismatch { .. }
+        ));
+        assert!(matches!(
+            Verdict::check(Some("42"), &failed()),
+            Verdict::Regression { .. }
+        ));
+        assert!(matches!(
+            Verdict::check(None, &run("42")),
+            Verdict::Missing { .. }
+        ));
+        assert!(matches!(
+            Verdict::check(None, &failed()),
+            Verdict::Failed { .. }
+        ));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::runner::{Day, Part};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Step::Parse => f.pad("parse"),
+            Step::Part1 => f.pad("part1"),
+            Step::Part2 => f.pad("part2"),
+        }
+    }
+}
+
+/// Median time of one step of a day over all samples.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timing {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub median_ns: u64,
+}
+
+impl Timing {
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+/// Timings saved with `aoc bench --save`, compared against later runs.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub timings: Vec<Timing>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("{}: {err}", path.display()))?;
+        serde_json::from_str(&content)
+            .map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    /// Writes the timings, keeping the ones of days that were not measured
+    /// this time from the existing file.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut merged = match Baseline::load(path) {
+            Ok(existing) => existing,
+            Err(_) if !path.exists() => Baseline::default(),
+            Err(err) => return Err(err),
+        };
+        merged.timings.retain(|old| {
+            self.timings
+                .iter()
+                .all(|new| (new.year, new.day) != (old.year, old.day))
+        });
+        merged.timings.extend(self.timings.iter().cloned());
+        merged
+            .timings
+            .sort_by_key(|timing| (timing.year, timing.day, timing.step));
+
+        let content = serde_json::to_string_pretty(&merged)
+            .map_err(|err| format!("{err}"))?;
+        fs::write(path, content + "\n")
+            .map_err(|err| format!("{}: {err}", path.display()))
+    }
+
+    pub fn get(&self, year: u16, day: u8, step: Step) -> Option<&Timing> {
+        self.timings.iter().find(|timing| {
+            (timing.year, timing.day, timing.step) == (year, day, step)
+        })
+    }
+}
+
+/// Runs both parts of a day `samples` times and keeps the median of each
+/// step. Parsing is timed once per part.
+pub fn measure(
+    day: &Day,
+    input: &str,
+    samples: usize,
+) -> Result<Vec<Timing>, String> {
+    let mut parse = vec![];
+    let mut part1 = vec![];
+    let mut part2 = vec![];
+    for _ in 0..samples {
+        for part in Part::BOTH {
+            let run = day.run(input, part)?;
+            parse.push(run.parse);
+            match part {
+                Part::One => part1.push(run.solve),
+                Part::Two => part2.push(run.solve),
+            }
+        }
+    }
+    Ok([
+        (Step::Parse, parse),
+        (Step::Part1, part1),
+        (Step::Part2, part2),
+    ]
+    .into_iter()
+    .map(|(step, durations)| Timing {
+        year: day.year,
+        day: day.number,
+        step,
+        median_ns: median(durations).as_nanos() as u64,
+    })
+    .collect())
+}
+
+fn median(mut durations: Vec<Duration>) -> Duration {
+    durations.sort();
+    durations
+        .get(durations.len() / 2)
+        .copied()
+        .unwrap_or_default()
+}
+
+/// Relative change of a timing against its baseline, e.g. `0.25` when it
+/// takes a quarter longer.
+pub fn change(baseline: &Timing, current: &Timing) -> f64 {
+    current.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0
+}
+
+/// Whether a change counts as a slowdown for a threshold in percent.
+pub fn is_slower(change: f64, threshold: f64) -> bool {
+    change * 100.0 > threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timing(day: u8, step: Step, median_ns: u64) -> Timing {
+        Timing {
+            year: 2024,
+            day,
+            step,
+            median_ns,
+        }
+    }
+
+    #[test]
+    fn test_median() {
+        let durations = [5, 1, 3].map(Duration::from_nanos).to_vec();
+        assert_eq!(median(durations), Duration::from_nanos(3));
+        assert_eq!(median(vec![]), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = timing(6, Step::Part2, 100);
+        let change = change(&baseline, &timing(6, Step::Part2, 125));
+        assert!((change - 0.25).abs() < 1e-9);
+        assert!(is_slower(change, 10.0));
+        assert!(!is_slower(change, 30.0));
+        assert!(!is_slower(-0.5, 10.0));
+    }
+
+    #[test]
+    fn test_save_merges_days() {
+        let path = std::env::temp_dir().join("aoc-bench-baseline.json");
+        let _ = fs::remove_file(&path);
+        let first = Baseline {
+            timings: vec![
+                timing(1, Step::Parse, 10),
+                timing(2, Step::Parse, 20),
+            ],
+        };
+        first.save(&path).unwrap();
+        let second = Baseline {
+            timings: vec![
+                timing(2, Step::Parse, 30),
+                Timing {
+                    year: 2025,
+                    ..timing(1, Step::Parse, 40)
+                },
+            ],
+        };
+        second.save(&path).unwrap();
+
+        let saved = Baseline::load(&path).unwrap();
+        assert_eq!(saved.get(2024, 1, Step::Parse).unwrap().median_ns, 10);
+        assert_eq!(saved.get(2024, 2, Step::Parse).unwrap().median_ns, 30);
+        assert_eq!(saved.get(2025, 1, Step::Parse).unwrap().median_ns, 40);
+        assert!(saved.get(2025, 2, Step::Parse).is_none());
+    }
+}
@@ -0,0 +1,213 @@
+use crate::runner::{Part, Run};
+use common::alloc::Usage;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// One part's answer and timings, as `aoc run` found them.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Entry {
+    part: u8,
+    input_sha256: String,
+    answer: String,
+    parse_ns: u64,
+    solve_ns: u64,
+    allocations: Option<u64>,
+    allocated_bytes: Option<u64>,
+    peak_heap_bytes: Option<u64>,
+}
+
+impl Entry {
+    fn memory(&self) -> Option<Usage> {
+        Some(Usage {
+            allocations: self.allocations?,
+            bytes: self.allocated_bytes?,
+            peak: self.peak_heap_bytes?,
+        })
+    }
+}
+
+/// The cached runs of one day, all from the source with this hash.
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct DayCache {
+    source_sha256: String,
+    entries: Vec<Entry>,
+}
+
+/// Answers and timings of earlier runs in `.cache/<year>/results/`, one
+/// file per day. A run is reused while both the input and the source of
+/// the day, and of `common` that it builds on, are unchanged.
+pub struct ResultCache {
+    dir: PathBuf,
+    /// Ignore what is cached, but still store new runs.
+    fresh: bool,
+}
+
+/// Keeps parts run side by side from writing the same file at once.
+static WRITING: Mutex<()> = Mutex::new(());
+
+impl ResultCache {
+    pub fn new(cache_dir: &Path, fresh: bool) -> Self {
+        ResultCache {
+            dir: cache_dir.to_path_buf(),
+            fresh,
+        }
+    }
+
+    fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join("results")
+            .join(format!("day{day}.json"))
+    }
+
+    fn load(&self, year: u16, day: u8) -> Option<DayCache> {
+        let content = fs::read_to_string(self.path(year, day)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    /// The cached run of `part` on the input with hash `input_sha256`; when
+    /// counting allocations, only one that has them.
+    pub fn get(
+        &self,
+        year: u16,
+        day: u8,
+        source: &str,
+        input_sha256: &str,
+        part: Part,
+        needs_memory: bool,
+    ) -> Option<Run> {
+        if self.fresh {
+            return None;
+        }
+        let cache = self.load(year, day)?;
+        if cache.source_sha256 != source {
+            return None;
+        }
+        let entry = cache.entries.into_iter().find(|entry| {
+            entry.part == part.number() && entry.input_sha256 == input_sha256
+        })?;
+        let memory = entry.memory();
+        if needs_memory && memory.is_none() {
+            return None;
+        }
+        Some(Run {
+            answer: entry.answer,
+            parse: Duration::from_nanos(entry.parse_ns),
+            solve: Duration::from_nanos(entry.solve_ns),
+            memory,
+        })
+    }
+
+    /// Stores a run, dropping whatever the day cached for another source.
+    pub fn put(
+        &self,
+        year: u16,
+        day: u8,
+        source: &str,
+        input_sha256: &str,
+        part: Part,
+        run: &Run,
+    ) -> Result<(), String> {
+        let nanos = |duration: Duration| {
+            u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+        };
+        let entry = Entry {
+            part: part.number(),
+            input_sha256: input_sha256.to_string(),
+            answer: run.answer.clone(),
+            parse_ns: nanos(run.parse),
+            solve_ns: nanos(run.solve),
+            allocations: run.memory.map(|memory| memory.allocations),
+            allocated_bytes: run.memory.map(|memory| memory.bytes),
+            peak_heap_bytes: run.memory.map(|memory| memory.peak),
+        };
+
+        let _writing = WRITING.lock().unwrap_or_else(|err| err.into_inner());
+        let mut cache = self
+            .load(year, day)
+            .filter(|cache| cache.source_sha256 == source)
+            .unwrap_or_else(|| DayCache {
+                source_sha256: source.to_string(),
+                entries: vec![],
+            });
+        cache.entries.retain(|old| {
+            (old.part, &old.input_sha256) != (entry.part, &entry.input_sha256)
+        });
+        cache.entries.push(entry);
+
+        let path = self.path(year, day);
+        let error = |err: io::Error| format!("{}: {err}", path.display());
+        fs::create_dir_all(path.parent().expect("the file is in a directory"))
+            .map_err(error)?;
+        let content = serde_json::to_string_pretty(&cache)
+            .map_err(|err| format!("{err}"))?;
+        // Written aside and moved in place, so a reader never sees half
+        let partial = path.with_extension("json.partial");
+        fs::write(&partial, content + "\n").map_err(error)?;
+        fs::rename(&partial, &path).map_err(error)
+    }
+}
+
+/// The hash of the sources the answers of `year`/`day` depend on, as
+/// `build.rs` found them when this binary was built; see `source.rs`.
+/// Hashing at build time keeps a stale binary from caching its answers
+/// under the hash of sources it was not built from.
+pub fn source_hash(year: u16, day: u8) -> Option<&'static str> {
+    let key = format!("{year}/{day}=");
+    env!("AOC_SOURCE_SHA256")
+        .split(' ')
+        .find_map(|entry| entry.strip_prefix(&key))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_cache() {
+        let dir = env::temp_dir().join("aoc-result-cache-test");
+        let _ = fs::remove_dir_all(&dir);
+        let cache = ResultCache::new(&dir, false);
+        let run = Run {
+            answer: "42".to_string(),
+            parse: Duration::from_nanos(5),
+            solve: Duration::from_nanos(10),
+            memory: None,
+        };
+        let get = |source, input, part| {
+            cache.get(2024, 3, source, input, part, false)
+        };
+
+        assert!(get("v1", "1 2", Part::One).is_none());
+        cache.put(2024, 3, "v1", "1 2", Part::One, &run).unwrap();
+        let cached = get("v1", "1 2", Part::One).unwrap();
+        assert_eq!(
+            (cached.answer, cached.solve),
+            (run.answer.clone(), run.solve)
+        );
+        assert!(get("v1", "1 2", Part::Two).is_none());
+        assert!(get("v1", "1 3", Part::One).is_none());
+        assert!(cache.get(2024, 3, "v1", "1 2", Part::One, true).is_none());
+        assert!(ResultCache::new(&dir, true)
+            .get(2024, 3, "v1", "1 2", Part::One, false)
+            .is_none());
+
+        // A new source drops the old runs
+        cache.put(2024, 3, "v2", "1 2", Part::Two, &run).unwrap();
+        assert!(get("v2", "1 2", Part::One).is_none());
+        assert!(get("v2", "1 2", Part::Two).is_some());
+    }
+
+    #[test]
+    fn test_source_hash() {
+        let hash = source_hash(2024, 1).unwrap();
+        assert_eq!(hash.len(), 64);
+        assert_ne!(Some(hash), source_hash(2024, 2));
+        assert_eq!(source_hash(2024, 0), None);
+    }
+}
@@ -0,0 +1,169 @@
+use crate::config::Config;
+use std::fs;
+use std::thread;
+use std::time::Duration;
+use ureq::Agent;
+
+const USER_AGENT: &str = "github.com/zh0uquan/adventofcode2024";
+
+/// Reply of the server, whatever its status.
+#[derive(Debug)]
+pub struct Reply {
+    pub status: u16,
+    pub body: String,
+}
+
+/// HTTP client for the Advent of Code server that sends the session cookie
+/// and keeps at least `Config::interval` between requests, also across runs.
+pub struct Client {
+    pub config: Config,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(config: Config) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+        Client { config, agent }
+    }
+
+    /// GETs a path below the year, e.g. `day/1/input`.
+    pub fn get(&self, path: &str) -> Result<Reply, String> {
+        let url = self.url(path);
+        let session = self.config.session()?;
+        self.wait_turn()?;
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &format!("session={session}"))
+            .call();
+        read_reply(&url, response)
+    }
+
+    /// POSTs a form to a path below the year, e.g. `day/1/answer`.
+    pub fn post(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<Reply, String> {
+        let url = self.url(path);
+        let session = self.config.session()?;
+        self.wait_turn()?;
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &format!("session={session}"))
+            .send_form(form.iter().copied());
+        read_reply(&url, response)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}/{path}", self.config.base_url, self.config.year)
+    }
+
+    /// Sleeps until the interval since the last request has passed. The
+    /// time of the last request is the mtime of a file in the cache.
+    fn wait_turn(&self) -> Result<(), String> {
+        let clock = self.config.cache_dir.join("last-request");
+        let since = fs::metadata(&clock)
+            .and_then(|meta| meta.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok());
+        if let Some(wait) =
+            since.and_then(|s| self.config.interval.checked_sub(s))
+        {
+            thread::sleep(wait);
+        }
+        fs::create_dir_all(&self.config.cache_dir)
+            .and_then(|_| fs::write(&clock, ""))
+            .map_err(|err| format!("{}: {err}", clock.display()))
+    }
+}
+
+fn read_reply(
+    url: &str,
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+) -> Result<Reply, String> {
+    let mut response = response.map_err(|err| format!("{url}: {err}"))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|err| format!("{url}: {err}"))?;
+    Ok(Reply { status, body })
+}
+
+/// A stand-in for the Advent of Code server in tests: answers each incoming
+/// connection with the next canned reply and records the requests.
+#[cfg(test)]
+pub mod mock {
+    use super::*;
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::path::PathBuf;
+    use std::sync::{Arc, Mutex};
+
+    pub struct Server {
+        pub url: String,
+        pub requests: Arc<Mutex<Vec<String>>>,
+    }
+
+    pub fn serve(replies: Vec<(u16, &'static str)>) -> Server {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+        let seen = requests.clone();
+        thread::spawn(move || {
+            for (status, body) in replies {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                seen.lock().unwrap().push(request);
+
+                let mut stream = reader.into_inner();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\n\
+                     Connection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        Server { url, requests }
+    }
+
+    /// Config pointing at `server` with a fresh cache and no rate limit.
+    pub fn config(server: &Server, name: &str) -> Config {
+        let cache_dir: PathBuf = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&cache_dir);
+        Config {
+            base_url: server.url.clone(),
+            year: 2024,
+            session: Some("secret".to_string()),
+            cache_dir,
+            interval: Duration::ZERO,
+        }
+    }
+}
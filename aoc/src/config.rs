@@ -0,0 +1,66 @@
+use crate::runner::workspace_root;
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2024;
+/// Minimum time between two requests to the server.
+const DEFAULT_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Settings for talking to the Advent of Code server, read from the
+/// environment and the workspace `.env` that the justfile also loads.
+#[derive(Debug, Clone)]
+pub struct Config {
+    /// `AOC_BASE_URL`, so tests can point at a local server.
+    pub base_url: String,
+    /// `AOC_YEAR`
+    pub year: u16,
+    /// `SESSION`, the session cookie of the logged in account.
+    pub session: Option<String>,
+    /// `AOC_CACHE_DIR`, where downloads and the request clock are kept.
+    pub cache_dir: PathBuf,
+    pub interval: Duration,
+}
+
+impl Config {
+    /// Reads the settings for the event of `year`.
+    pub fn from_env(year: u16) -> Result<Self, String> {
+        load_dotenv();
+        Ok(Config {
+            base_url: env::var("AOC_BASE_URL")
+                .unwrap_or_else(|_| DEFAULT_BASE_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            year,
+            session: env::var("SESSION").ok().filter(|s| !s.is_empty()),
+            cache_dir: env::var_os("AOC_CACHE_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| workspace_root().join(".cache")),
+            interval: DEFAULT_INTERVAL,
+        })
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session
+            .as_deref()
+            .ok_or_else(|| "SESSION is not set, add it to .env".to_string())
+    }
+}
+
+fn load_dotenv() {
+    // A missing .env is fine, the variables may be exported already.
+    let _ = dotenvy::from_path(workspace_root().join(".env"));
+}
+
+/// The event for days given without a year: `AOC_YEAR` if set, else
+/// `fallback`.
+pub fn year(fallback: u16) -> Result<u16, String> {
+    load_dotenv();
+    match env::var("AOC_YEAR") {
+        Ok(year) => year
+            .parse()
+            .map_err(|_| format!("AOC_YEAR is not a year: {year:?}")),
+        Err(_) => Ok(fallback),
+    }
+}
@@ -0,0 +1,191 @@
+use common::stepper::{Cursor, Tape};
+use common::viz::Frame;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::{Color, Print, ResetColor, SetForegroundColor};
+use crossterm::{cursor, execute, queue, terminal};
+use std::io::{self, Write};
+
+const HELP: &str =
+    "←/→ step  ↑/↓ 10  PgUp/PgDn 100  Home/End  g go to step  q quit";
+
+/// Steps through `tape` in the terminal until the user quits, starting at
+/// step `at`.
+pub fn run(title: &str, tape: &Tape, at: usize) -> io::Result<()> {
+    let Some(mut cursor) = tape.cursor() else {
+        println!("{title}: nothing to step through");
+        return Ok(());
+    };
+    cursor.goto(at);
+
+    let mut out = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(out, terminal::EnterAlternateScreen, cursor::Hide)?;
+    let result = interact(&mut out, title, tape, &mut cursor);
+    execute!(out, cursor::Show, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+    result
+}
+
+fn interact(
+    out: &mut impl Write,
+    title: &str,
+    tape: &Tape,
+    cursor: &mut Cursor,
+) -> io::Result<()> {
+    // Digits typed after `g`, until Enter
+    let mut jump: Option<String> = None;
+    loop {
+        let (width, height) = terminal::size()?;
+        let footer = match &jump {
+            Some(digits) => format!("go to step: {digits}"),
+            None => HELP.to_string(),
+        };
+        let header = header(title, tape, cursor);
+        draw(out, &header, cursor, &footer, (width, height))?;
+
+        let Event::Key(KeyEvent { code, kind, .. }) = event::read()? else {
+            continue;
+        };
+        if kind == KeyEventKind::Release {
+            continue;
+        }
+        if let Some(digits) = &mut jump {
+            match code {
+                KeyCode::Char(c) if c.is_ascii_digit() => digits.push(c),
+                KeyCode::Backspace => {
+                    digits.pop();
+                }
+                KeyCode::Enter => {
+                    if let Ok(step) = digits.parse() {
+                        cursor.goto(step);
+                    }
+                    jump = None;
+                }
+                KeyCode::Esc => jump = None,
+                _ => {}
+            }
+            continue;
+        }
+        match code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Right | KeyCode::Char('l') | KeyCode::Char(' ') => {
+                cursor.step_by(1)
+            }
+            KeyCode::Left | KeyCode::Char('h') => cursor.step_by(-1),
+            KeyCode::Down | KeyCode::Char('j') => cursor.step_by(10),
+            KeyCode::Up | KeyCode::Char('k') => cursor.step_by(-10),
+            KeyCode::PageDown => cursor.step_by(100),
+            KeyCode::PageUp => cursor.step_by(-100),
+            KeyCode::Home => cursor.goto(0),
+            KeyCode::End => cursor.goto(usize::MAX),
+            KeyCode::Char('g') => jump = Some(String::new()),
+            _ => {}
+        }
+    }
+}
+
+fn draw(
+    out: &mut impl Write,
+    header: &str,
+    cursor: &Cursor,
+    footer: &str,
+    (width, height): (u16, u16),
+) -> io::Result<()> {
+    let (width, height) = (width as usize, height as usize);
+    queue!(
+        out,
+        terminal::Clear(terminal::ClearType::All),
+        cursor::MoveTo(0, 0),
+        Print(truncate(header, width)),
+    )?;
+
+    let panel = cursor.panel();
+    let panel_width = panel.iter().map(|l| l.chars().count()).max();
+    let panel_width = panel_width.map_or(0, |w| w + 2);
+    let rows = height.saturating_sub(3);
+    let cols = width.saturating_sub(panel_width);
+    let frame = cursor.frame();
+    let (top, left) = viewport(frame, cursor.focus(), rows, cols);
+
+    for line in 0..rows.min(frame.height.max(panel.len())) {
+        queue!(out, cursor::MoveTo(0, line as u16 + 2))?;
+        let row = top + line;
+        let shown = frame.width.min(cols);
+        if row < frame.height {
+            for col in left..left + shown {
+                let cell = frame.get(row, col);
+                match cell.color {
+                    Some([r, g, b]) => queue!(
+                        out,
+                        SetForegroundColor(Color::Rgb { r, g, b }),
+                        Print(cell.ch),
+                        ResetColor
+                    )?,
+                    None => queue!(out, Print(cell.ch))?,
+                }
+            }
+        } else {
+            queue!(out, Print(" ".repeat(shown)))?;
+        }
+        if let Some(text) = panel.get(line) {
+            let room = width.saturating_sub(shown + 2);
+            queue!(out, Print("  "), Print(truncate(text, room)))?;
+        }
+    }
+    queue!(
+        out,
+        cursor::MoveTo(0, height.saturating_sub(1) as u16),
+        Print(truncate(footer, width))
+    )?;
+    out.flush()
+}
+
+fn header(title: &str, tape: &Tape, cursor: &Cursor) -> String {
+    format!(
+        "{title}  step {}/{}  {}",
+        cursor.position(),
+        tape.len() - 1,
+        cursor.label()
+    )
+}
+
+/// The top row and left column to draw from so that `focus` stays in the
+/// middle of a grid larger than the screen.
+fn viewport(
+    frame: &Frame,
+    focus: Option<(usize, usize)>,
+    rows: usize,
+    cols: usize,
+) -> (usize, usize) {
+    let (row, col) = focus.unwrap_or((0, 0));
+    let start = |at: usize, shown: usize, size: usize| {
+        at.saturating_sub(shown / 2).min(size.saturating_sub(shown))
+    };
+    (
+        start(row, rows, frame.height),
+        start(col, cols, frame.width),
+    )
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
+
+/// Prints step `at` as plain text, for when stdout is not a terminal.
+pub fn print(title: &str, tape: &Tape, at: usize) {
+    let Some(mut cursor) = tape.cursor() else {
+        println!("{title}: nothing to step through");
+        return;
+    };
+    cursor.goto(at);
+    println!("{}", header(title, tape, &cursor));
+    let frame = cursor.frame();
+    for row in 0..frame.height {
+        let line: String =
+            (0..frame.width).map(|col| frame.get(row, col).ch).collect();
+        println!("{line}");
+    }
+    for line in cursor.panel() {
+        println!("{line}");
+    }
+}
@@ -0,0 +1,140 @@
+use crate::client::Client;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Phrases of the server's error pages, which come back with any status.
+const ERROR_PAGES: [&str; 3] = [
+    "Puzzle inputs differ by user",
+    "Please don't repeatedly request this endpoint",
+    "404 Not Found",
+];
+
+pub fn cache_path(client: &Client, day: u8) -> PathBuf {
+    client
+        .config
+        .cache_dir
+        .join(client.config.year.to_string())
+        .join(format!("day{day}.txt"))
+}
+
+/// Returns the input of a day from the cache, downloading it on a miss.
+pub fn fetch(client: &Client, day: u8) -> Result<String, String> {
+    let cached = cache_path(client, day);
+    if let Ok(input) = fs::read_to_string(&cached) {
+        if check_input(&input).is_ok() {
+            return Ok(input);
+        }
+    }
+
+    let reply = client.get(&format!("day/{day}/input"))?;
+    match reply.status {
+        200 => {}
+        404 => return Err(format!("day {day} is not unlocked yet")),
+        400 | 500 if reply.body.contains(ERROR_PAGES[0]) => {
+            return Err("the session cookie was rejected".to_string())
+        }
+        status => {
+            return Err(format!(
+                "server answered {status}: {}",
+                first_line(&reply.body)
+            ))
+        }
+    }
+    check_input(&reply.body)?;
+
+    fs::create_dir_all(cached.parent().expect("cache path has a parent"))
+        .and_then(|_| fs::write(&cached, &reply.body))
+        .map_err(|err| format!("{}: {err}", cached.display()))?;
+    Ok(reply.body)
+}
+
+/// Rejects bodies that are an error page rather than a puzzle input.
+pub fn check_input(input: &str) -> Result<(), String> {
+    if input.trim().is_empty() {
+        return Err("the input is empty".to_string());
+    }
+    if input.trim_start().starts_with('<') {
+        return Err("the input is an HTML page".to_string());
+    }
+    if ERROR_PAGES.iter().any(|page| input.contains(page)) {
+        return Err(format!("the input is an error: {}", first_line(input)));
+    }
+    Ok(())
+}
+
+/// Writes a fetched input to `path` unless a valid input is already there.
+pub fn install(path: &Path, input: &str, force: bool) -> Result<bool, String> {
+    let existing = fs::read_to_string(path).ok();
+    if let Some(existing) = existing {
+        if existing == input {
+            return Ok(false);
+        }
+        if !force && check_input(&existing).is_ok() {
+            return Err(format!(
+                "{} already holds a different input, pass --force",
+                path.display()
+            ));
+        }
+    }
+    fs::write(path, input)
+        .map_err(|err| format!("{}: {err}", path.display()))?;
+    Ok(true)
+}
+
+fn first_line(body: &str) -> &str {
+    body.lines()
+        .find(|line| !line.trim().is_empty())
+        .unwrap_or("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock;
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let server = mock::serve(vec![(200, "1 2\n3 4\n")]);
+        let client = Client::new(mock::config(&server, "aoc-fetch-cache"));
+
+        assert_eq!(fetch(&client, 1).unwrap(), "1 2\n3 4\n");
+        assert_eq!(fetch(&client, 1).unwrap(), "1 2\n3 4\n");
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2024/day/1/input "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn test_fetch_rejects_error_pages() {
+        let server = mock::serve(vec![
+            (400, "Puzzle inputs differ by user.  Please log in."),
+            (200, "Please don't repeatedly request this endpoint."),
+            (404, "Not Found"),
+        ]);
+        let client = Client::new(mock::config(&server, "aoc-fetch-errors"));
+
+        assert_eq!(
+            fetch(&client, 2).unwrap_err(),
+            "the session cookie was rejected"
+        );
+        assert!(fetch(&client, 2).unwrap_err().contains("repeatedly"));
+        assert_eq!(
+            fetch(&client, 2).unwrap_err(),
+            "day 2 is not unlocked yet"
+        );
+        assert!(!cache_path(&client, 2).exists());
+    }
+
+    #[test]
+    fn test_install_keeps_valid_input() {
+        let path = std::env::temp_dir().join("aoc-fetch-install.txt");
+        fs::write(&path, "<html>oops</html>").unwrap();
+        assert_eq!(install(&path, "1 2\n", false), Ok(true));
+        assert!(install(&path, "3 4\n", false).is_err());
+        assert_eq!(install(&path, "1 2\n", false), Ok(false));
+        assert_eq!(install(&path, "3 4\n", true), Ok(true));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "3 4\n");
+    }
+}
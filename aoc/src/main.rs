@@ -0,0 +1,1223 @@
+mod answers;
+mod bench;
+mod cache;
+mod client;
+mod config;
+mod debugger;
+mod fetch;
+mod registry;
+mod report;
+mod runner;
+mod scaffold;
+mod serve;
+// Run by build.rs, and only compiled here for its tests
+#[cfg(test)]
+mod source;
+mod submit;
+mod vault;
+
+use answers::{Answers, Verdict, DEFAULT_INPUT};
+use bench::Baseline;
+use cache::ResultCache;
+use clap::{ArgAction, Args, Parser, Subcommand, ValueEnum};
+use client::Client;
+use common::budget::Budget;
+use common::crypt::{self, Key};
+use common::example::Example;
+use common::input::{encrypted_path, Source};
+use common::stepper::Tape;
+use common::viz::{self, ImageFormat, Recording};
+use config::Config;
+use rayon::prelude::*;
+use report::{Format, Record};
+use runner::{
+    day_dir, format_bytes, format_duration, peak_memory, workspace_root, Day,
+    Part, Run, COUNTS_ALLOCATIONS,
+};
+use std::io::{self, IsTerminal};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+use tracing::warn;
+use tracing_subscriber::filter::LevelFilter;
+
+#[cfg(feature = "count-allocs")]
+#[global_allocator]
+static ALLOCATOR: common::alloc::Counting = common::alloc::Counting;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions of every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log what the solvers do to stderr, -vv for every step
+    #[arg(short, long, action = ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Event for days given without a year, like `16` for `2024/16`; by
+    /// default AOC_YEAR or the latest year with a solution
+    #[arg(long, global = true, value_parser = parse_year)]
+    year: Option<u16>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day with `all`
+    Run {
+        /// Day like `16` or `2024/16`, or `all` days of a year like
+        /// `2024/all`
+        day: Selection,
+        /// Only run this part
+        #[arg(long)]
+        part: Option<Part>,
+        #[command(flatten)]
+        input: InputArgs,
+        /// Run every day and part at once on a thread pool
+        #[arg(long)]
+        parallel: bool,
+        /// Threads for --parallel, by default one per CPU
+        #[arg(long, requires = "parallel")]
+        jobs: Option<usize>,
+        /// Print a table, JSON lines or CSV
+        #[arg(long, value_enum, default_value_t)]
+        format: Format,
+        #[command(flatten)]
+        budget: BudgetArgs,
+        /// Run every part again instead of reusing cached answers
+        #[arg(long)]
+        fresh: bool,
+    },
+    /// Check answers against each day's answers.toml
+    Verify {
+        /// Day like `16` or `2024/16`, or `all` days of a year like
+        /// `2024/all`
+        #[arg(default_value = "all")]
+        day: Selection,
+        /// Write answers that are not recorded yet to answers.toml
+        #[arg(long)]
+        record: bool,
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Time every step of one day, or every day, on its default input
+    Bench {
+        /// Day like `16` or `2024/16`, or `all` days of a year like
+        /// `2024/all`
+        #[arg(default_value = "all")]
+        day: Selection,
+        /// Runs of each part to take the median of
+        #[arg(long, default_value_t = 5)]
+        samples: usize,
+        /// Save the medians to this JSON baseline
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare the medians with this JSON baseline
+        #[arg(long)]
+        compare: Option<PathBuf>,
+        /// Slowdown in percent that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Download a day's input into YEAR/dayN/src/input.txt, and encrypt it
+    /// when AOC_INPUT_KEY is set
+    Fetch {
+        /// Day number, like `16`, `day16` or `2024/16`
+        #[arg(value_parser = parse_day)]
+        day: DayRef,
+        /// Replace an input that is already there
+        #[arg(long)]
+        force: bool,
+    },
+    /// Encrypt every day's src/input.txt into src/input.txt.enc with the
+    /// key in AOC_INPUT_KEY
+    Encrypt {
+        /// Encrypt every input with a new key instead, generated unless
+        /// given with --new-key
+        #[arg(long)]
+        rotate: bool,
+        /// Key to rotate to, 64 hex digits
+        #[arg(long, requires = "rotate")]
+        new_key: Option<String>,
+    },
+    /// Create dayN from daily-template and register it with the runner
+    New {
+        /// Day number, like `16`, `day16` or `2024/16`
+        #[arg(value_parser = parse_day)]
+        day: DayRef,
+    },
+    /// Record a day's simulation as a GIF, images or a terminal replay
+    Viz {
+        /// Day number, like `16`, `day16` or `2024/16`
+        #[arg(value_parser = parse_day)]
+        day: DayRef,
+        /// What to write
+        #[arg(long, value_enum, default_value_t = VizFormat::Gif)]
+        format: VizFormat,
+        /// File for a GIF or directory for images, by default dayN.gif or
+        /// dayN-frames/
+        #[arg(long)]
+        out: Option<PathBuf>,
+        /// Pixels per cell in images
+        #[arg(long, default_value_t = 4)]
+        scale: usize,
+        /// Milliseconds between frames
+        #[arg(long, default_value_t = 50)]
+        delay: u64,
+        /// Most frames to keep; longer runs keep evenly spaced ones
+        #[arg(long, default_value_t = viz::DEFAULT_LIMIT)]
+        frames: usize,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Step through a day's simulation in the terminal
+    Debug {
+        /// Day number, like `15`, `day15` or `2024/15`
+        #[arg(value_parser = parse_day)]
+        day: DayRef,
+        /// The part whose simulation to step through
+        #[arg(long, default_value = "1")]
+        part: Part,
+        /// Step to start at, or to print when stdout is not a terminal
+        #[arg(long, default_value_t = 0)]
+        at: usize,
+        #[command(flatten)]
+        input: InputArgs,
+    },
+    /// Serve the solvers over HTTP, with a page to paste an input into
+    Serve {
+        /// Port to listen on
+        #[arg(long, default_value_t = 8024)]
+        port: u16,
+        /// Address to listen on; 0.0.0.0 for the whole network
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+        /// Inputs solved at once, by default one per CPU
+        #[arg(long)]
+        workers: Option<NonZeroUsize>,
+        #[command(flatten)]
+        budget: BudgetArgs,
+    },
+    /// Submit an answer, by default the one the solver gives
+    Submit {
+        /// Day number, like `16`, `day16` or `2024/16`
+        #[arg(value_parser = parse_day)]
+        day: DayRef,
+        /// Part 1 or 2
+        part: Part,
+        /// Answer to send instead of running the solver
+        answer: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum VizFormat {
+    /// An animated GIF
+    Gif,
+    /// One PPM image per frame
+    Ppm,
+    /// One PNG image per frame
+    Png,
+    /// Play the frames in the terminal
+    Ansi,
+}
+
+/// A day given on the command line, with its year if it has one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DayRef {
+    year: Option<u16>,
+    day: u8,
+}
+
+impl DayRef {
+    /// The year and day, taking `year` for a day given without one.
+    fn resolve(self, year: u16) -> (u16, u8) {
+        (self.year.unwrap_or(year), self.day)
+    }
+}
+
+fn parse_year(s: &str) -> Result<u16, String> {
+    s.parse()
+        .ok()
+        .filter(|&year| year >= 2015)
+        .ok_or_else(|| format!("not a year of Advent of Code: {s:?}"))
+}
+
+/// Parses `16`, `day16`, `2024/16` or `2024/day16`.
+fn parse_day(s: &str) -> Result<DayRef, String> {
+    let (year, day) = match s.split_once('/') {
+        Some((year, day)) => (Some(parse_year(year)?), day),
+        None => (None, s),
+    };
+    let day = day
+        .trim_start_matches("day")
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or_else(|| format!("not a day of December: {s:?}"))?;
+    Ok(DayRef { year, day })
+}
+
+fn find_day(year: u16, day: u8) -> Result<&'static Day, String> {
+    registry::find(year, day)
+        .ok_or_else(|| format!("no solution for {year} day {day}"))
+}
+
+#[derive(Args)]
+struct InputArgs {
+    /// Read the input from this file, or from stdin with `-`
+    #[arg(long, conflicts_with = "example")]
+    input: Option<PathBuf>,
+    /// Use the day's examples/NAME.txt as input
+    #[arg(long)]
+    example: Option<String>,
+}
+
+impl InputArgs {
+    fn source(&self) -> Source {
+        match (&self.input, &self.example) {
+            (Some(path), _) if path.as_os_str() == "-" => Source::Stdin,
+            (Some(path), _) => Source::File(path.clone()),
+            (None, Some(name)) => Source::Example(name.clone()),
+            (None, None) => Source::Default,
+        }
+    }
+}
+
+#[derive(Args)]
+struct BudgetArgs {
+    /// Seconds a part may take before it is cancelled, 0 for no limit
+    #[arg(long, default_value_t = 60.0)]
+    time_budget: f64,
+    /// MiB of heap a part may hold before it is cancelled, with the
+    /// count-allocs feature
+    #[arg(long)]
+    memory_budget: Option<u64>,
+}
+
+impl BudgetArgs {
+    fn budget(&self) -> Result<Budget, String> {
+        let time = Duration::try_from_secs_f64(self.time_budget)
+            .map_err(|_| "--time-budget must be a number of seconds")?;
+        if self.memory_budget.is_some() && !COUNTS_ALLOCATIONS {
+            return Err(
+                "--memory-budget needs the count-allocs feature".into()
+            );
+        }
+        Ok(Budget {
+            time: Some(time).filter(|time| !time.is_zero()),
+            memory: self.memory_budget.map(|mib| mib << 20),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    /// Every day of a year, if given
+    All(Option<u16>),
+    Day(DayRef),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('/') {
+            Some((year, "all")) => Ok(Selection::All(Some(parse_year(year)?))),
+            None if s == "all" => Ok(Selection::All(None)),
+            _ => parse_day(s).map(Selection::Day),
+        }
+    }
+}
+
+impl Selection {
+    /// The selected days, taking `year` for a selection without one.
+    fn days(self, year: u16) -> Result<Vec<&'static Day>, String> {
+        match self {
+            Selection::Day(day) => {
+                let (year, day) = day.resolve(year);
+                Ok(vec![find_day(year, day)?])
+            }
+            Selection::All(selected) => {
+                let year = selected.unwrap_or(year);
+                let days: Vec<&Day> = registry::year(year).collect();
+                if days.is_empty() {
+                    return Err(format!("no solutions for {year}"));
+                }
+                Ok(days)
+            }
+        }
+    }
+}
+
+struct Row {
+    year: u16,
+    day: u8,
+    part: Part,
+    outcome: Result<Run, String>,
+    /// The check against answers.toml, for the default input only.
+    verdict: Option<Verdict>,
+    input_hash: Option<String>,
+    /// Whether the run comes from the result cache.
+    cached: bool,
+}
+
+impl Row {
+    fn failed(&self) -> bool {
+        self.outcome.is_err()
+            || self.verdict.as_ref().is_some_and(Verdict::is_failure)
+    }
+}
+
+fn run_day(
+    day: &Day,
+    parts: &[Part],
+    source: &Source,
+    budget: Budget,
+    cache: &ResultCache,
+) -> Vec<Row> {
+    let input = day
+        .load(source)
+        .map_err(|err| format!("cannot load input: {err}"));
+    let input_hash = input.as_deref().ok().map(report::input_hash);
+    // Without a hash of the source nothing is cached
+    let source_hash = cache::source_hash(day.year, day.number);
+    // An example carries its answers, so a wrong one fails the run
+    let example = match source {
+        Source::Example(name) => Example::load(&day.dir(), name).ok(),
+        _ => None,
+    };
+    let answers = match source {
+        Source::Default => Answers::load(&day.dir()).ok(),
+        _ => None,
+    };
+    parts
+        .iter()
+        .map(|&part| {
+            let expected = example
+                .as_ref()
+                .and_then(|example| example.expected(&format!("part{part}")));
+            let keys = source_hash.zip(input_hash.as_deref());
+            let cached = keys.and_then(|(source, input)| {
+                let (year, number) = (day.year, day.number);
+                cache.get(
+                    year,
+                    number,
+                    source,
+                    input,
+                    part,
+                    COUNTS_ALLOCATIONS,
+                )
+            });
+            let was_cached = cached.is_some();
+            let outcome = match cached {
+                Some(run) => Ok(run),
+                None => {
+                    input.as_ref().map_err(Clone::clone).and_then(|input| {
+                        let run = day.run_within(input, part, budget)?;
+                        if let Some((source, input)) = keys {
+                            let stored = cache.put(
+                                day.year, day.number, source, input, part,
+                                &run,
+                            );
+                            if let Err(err) = stored {
+                                warn!("cannot cache the answer: {err}");
+                            }
+                        }
+                        Ok(run)
+                    })
+                }
+            };
+            let outcome = outcome.and_then(|run| match expected {
+                Some(expected) if expected != run.answer => Err(format!(
+                    "{}, the example expects {expected}",
+                    run.answer
+                )),
+                _ => Ok(run),
+            });
+            let verdict = answers.as_ref().map(|answers| {
+                let expected =
+                    answers.get(DEFAULT_INPUT).and_then(|e| e.get(part));
+                Verdict::check(expected, &outcome)
+            });
+            Row {
+                year: day.year,
+                day: day.number,
+                part,
+                outcome,
+                verdict,
+                input_hash: input_hash.clone(),
+                cached: was_cached,
+            }
+        })
+        .collect()
+}
+
+/// Runs every part of `days` as its own job on a pool of `jobs` threads, or
+/// one per CPU. The rows come back in day and part order.
+fn run_parallel(
+    days: &[&Day],
+    parts: &[Part],
+    source: &Source,
+    jobs: Option<usize>,
+    budget: Budget,
+    cache: &ResultCache,
+) -> Result<Vec<Row>, String> {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(jobs.unwrap_or(0))
+        .build()
+        .map_err(|err| format!("cannot start the thread pool: {err}"))?;
+    let tasks: Vec<(&Day, Part)> = days
+        .iter()
+        .flat_map(|&day| parts.iter().map(move |&part| (day, part)))
+        .collect();
+    Ok(pool.install(|| {
+        tasks
+            .par_iter()
+            .flat_map_iter(|&(day, part)| {
+                run_day(day, &[part], source, budget, cache)
+            })
+            .collect()
+    }))
+}
+
+fn print_records(rows: &[Row], format: Format) -> Result<(), String> {
+    let records: Vec<Record> = rows
+        .iter()
+        .map(|row| Record {
+            cached: row.cached,
+            ..Record::new(
+                row.year,
+                row.day,
+                row.part,
+                &row.outcome,
+                row.input_hash.clone(),
+                row.verdict.as_ref(),
+            )
+        })
+        .collect();
+    let out = io::stdout().lock();
+    match format {
+        Format::Json => report::write_json(out, &records),
+        Format::Csv => report::write_csv(out, &records),
+        Format::Table => unreachable!("tables are printed by print_table"),
+    }
+}
+
+fn print_rows(rows: &[Row]) {
+    for row in rows {
+        match &row.outcome {
+            Ok(run) => println!(
+                "{}/day {} part {}: {} (parse {}, solve {}{}{})",
+                row.year,
+                row.day,
+                row.part,
+                run.answer,
+                format_duration(run.parse),
+                format_duration(run.solve),
+                run.memory.map_or(String::new(), |memory| format!(
+                    ", {} allocations of {}, peak heap {}",
+                    memory.allocations,
+                    format_bytes(memory.bytes),
+                    format_bytes(memory.peak)
+                )),
+                if row.cached { ", cached" } else { "" }
+            ),
+            Err(err) => {
+                println!(
+                    "{}/day {} part {}: failed: {err}",
+                    row.year, row.day, row.part
+                )
+            }
+        }
+    }
+}
+
+fn print_table(rows: &[Row], wall: Duration) {
+    let answers: Vec<String> = rows
+        .iter()
+        .map(|row| match &row.outcome {
+            Ok(run) => run.answer.clone(),
+            // Only the first line of a diagnostic fits in the table
+            Err(err) => {
+                format!("failed: {}", err.lines().next().unwrap_or(""))
+            }
+        })
+        .collect();
+    let width = answers.iter().map(|a| a.chars().count()).max().unwrap_or(0);
+    let width = width.max("answer".len());
+
+    let memory_header = match COUNTS_ALLOCATIONS {
+        true => format!("{:>9}  {:>9}  {:>9}  ", "allocs", "bytes", "peak"),
+        false => String::new(),
+    };
+    println!(
+        "{:>4}  {:>3}  {:>4}  {:<width$}  {:>9}  {:>9}  {:>9}  \
+         {memory_header}check",
+        "year", "day", "part", "answer", "parse", "solve", "total"
+    );
+    for (row, answer) in rows.iter().zip(&answers) {
+        let [parse, solve, total] = match &row.outcome {
+            Ok(run) => {
+                [run.parse, run.solve, run.total()].map(format_duration)
+            }
+            Err(_) => ["-", "-", "-"].map(String::from),
+        };
+        let memory = match row.outcome.as_ref().map(|run| run.memory) {
+            Ok(Some(memory)) => format!(
+                "{:>9}  {:>9}  {:>9}  ",
+                memory.allocations,
+                format_bytes(memory.bytes),
+                format_bytes(memory.peak)
+            ),
+            _ if COUNTS_ALLOCATIONS => {
+                format!("{:>9}  {:>9}  {:>9}  ", "-", "-", "-")
+            }
+            _ => String::new(),
+        };
+        let check = row.verdict.as_ref().map_or("-", Verdict::label);
+        let cached = if row.cached { " (cached)" } else { "" };
+        println!(
+            "{:>4}  {:>3}  {:>4}  {:<width$}  {:>9}  {:>9}  {:>9}  \
+             {memory}{check}{cached}",
+            row.year, row.day, row.part, answer, parse, solve, total
+        );
+    }
+
+    let total: Duration = rows
+        .iter()
+        .filter_map(|row| row.outcome.as_ref().ok())
+        .map(Run::total)
+        .sum();
+    let passed = rows
+        .iter()
+        .filter(|row| matches!(row.verdict, Some(Verdict::Pass)))
+        .count();
+    let failed = rows.iter().filter(|row| row.failed()).count();
+    let cached = rows.iter().filter(|row| row.cached).count();
+    let memory = peak_memory().map_or("-".to_string(), format_bytes);
+    println!(
+        "{} parts in {} ({} wall clock), peak memory {memory}, \
+         {passed} ok, {failed} failed, {cached} cached",
+        rows.len(),
+        format_duration(total),
+        format_duration(wall)
+    );
+}
+
+/// Counts per verdict kind, in the order they are reported.
+#[derive(Default)]
+struct Tally {
+    passed: usize,
+    mismatched: usize,
+    regressed: usize,
+    missing: usize,
+    failed: usize,
+    skipped: usize,
+}
+
+fn verify_day(
+    day: &Day,
+    record: bool,
+    budget: Budget,
+    tally: &mut Tally,
+) -> Result<(), String> {
+    let dir = day.dir();
+    let mut answers = Answers::load(&dir)?;
+    let mut recorded = false;
+    for input in answers.inputs() {
+        // The default input may be embedded or encrypted
+        let source = match input.as_str() {
+            DEFAULT_INPUT => Source::Default,
+            _ => Source::File(dir.join(&input)),
+        };
+        let content = match day.load(&source) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                let reason = match source {
+                    Source::Default if encrypted_path(&dir).exists() => {
+                        format!("no {} to decrypt the input", crypt::KEY_VAR)
+                    }
+                    _ => "no input".to_string(),
+                };
+                println!(
+                    "{}/day {} {input}: skipped, {reason}",
+                    day.year, day.number
+                );
+                tally.skipped += Part::BOTH.len();
+                continue;
+            }
+            Err(err) => return Err(format!("cannot load input: {err}")),
+        };
+        for part in Part::BOTH {
+            let outcome = day.run_within(&content, part, budget);
+            let expected = answers.get(&input).and_then(|e| e.get(part));
+            let verdict = Verdict::check(expected, &outcome);
+            println!(
+                "{}/day {} part {part} {input}: {verdict}",
+                day.year, day.number
+            );
+            match verdict {
+                Verdict::Pass => tally.passed += 1,
+                Verdict::Mismatch { .. } => tally.mismatched += 1,
+                Verdict::Regression { .. } => tally.regressed += 1,
+                Verdict::Failed { .. } => tally.failed += 1,
+                Verdict::Missing { actual } => {
+                    tally.missing += 1;
+                    if record {
+                        answers.entry(&input).set(part, actual);
+                        recorded = true;
+                    }
+                }
+            }
+        }
+    }
+    if recorded {
+        answers.save(&dir)?;
+    }
+    Ok(())
+}
+
+fn verify(days: &[&Day], record: bool, budget: Budget) -> ExitCode {
+    let mut tally = Tally::default();
+    for day in days {
+        if let Err(err) = verify_day(day, record, budget, &mut tally) {
+            println!("{}/day {}: FAILED {err}", day.year, day.number);
+            tally.failed += 1;
+        }
+    }
+    println!(
+        "{} ok, {} mismatched, {} regressed, {} failed, {} missing, \
+         {} skipped",
+        tally.passed,
+        tally.mismatched,
+        tally.regressed,
+        tally.failed,
+        tally.missing,
+        tally.skipped
+    );
+    if tally.mismatched + tally.regressed + tally.failed > 0 {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+struct BenchArgs {
+    samples: usize,
+    save: Option<PathBuf>,
+    compare: Option<PathBuf>,
+    threshold: f64,
+}
+
+fn bench(days: &[&Day], args: BenchArgs) -> Result<bool, String> {
+    let baseline = args.compare.as_deref().map(Baseline::load).transpose()?;
+    let mut measured = Baseline::default();
+    let mut slower = 0;
+
+    println!(
+        "{:>3}  {:<5}  {:>9}  {:>9}  {:>8}",
+        "day", "step", "median", "baseline", "change"
+    );
+    for day in days {
+        let timings = day
+            .load(&Source::Default)
+            .map_err(|err| format!("cannot load input: {err}"))
+            .and_then(|input| bench::measure(day, &input, args.samples));
+        let timings = match timings {
+            Ok(timings) => timings,
+            Err(err) => {
+                println!("{:>3}  failed: {err}", day.number);
+                continue;
+            }
+        };
+        for timing in &timings {
+            let old = baseline.as_ref().and_then(|baseline| {
+                baseline.get(timing.year, timing.day, timing.step)
+            });
+            let (old, change) = match old {
+                Some(old) => {
+                    let change = bench::change(old, timing);
+                    let mark = if bench::is_slower(change, args.threshold) {
+                        slower += 1;
+                        " SLOWER"
+                    } else {
+                        ""
+                    };
+                    (
+                        format_duration(old.median()),
+                        format!("{:+.1}%{mark}", change * 100.0),
+                    )
+                }
+                None => ("-".to_string(), "-".to_string()),
+            };
+            println!(
+                "{:>3}  {:<5}  {:>9}  {:>9}  {:>8}",
+                timing.day,
+                timing.step,
+                format_duration(timing.median()),
+                old,
+                change
+            );
+        }
+        measured.timings.extend(timings);
+    }
+
+    if let Some(path) = &args.save {
+        measured.save(path)?;
+        println!(
+            "saved {} timings to {}",
+            measured.timings.len(),
+            path.display()
+        );
+    }
+    if baseline.is_some() {
+        println!(
+            "{slower} steps slower than the baseline by more than {}%",
+            args.threshold
+        );
+    }
+    Ok(slower == 0 && measured.timings.len() == days.len() * 3)
+}
+
+fn fetch_input(year: u16, day: u8, force: bool) -> Result<(), String> {
+    let dir = day_dir(workspace_root(), year, day);
+    if !dir.is_dir() {
+        return Err(format!("there is no crate {}", dir.display()));
+    }
+    let path = dir.join("src").join("input.txt");
+    let existing = std::fs::read_to_string(&path).unwrap_or_default();
+    if !force && fetch::check_input(&existing).is_ok() {
+        println!("{} is already there", path.display());
+        return Ok(());
+    }
+    let encrypted = encrypted_path(&dir);
+    if !force && !path.exists() && encrypted.exists() {
+        println!("{} is already there", encrypted.display());
+        return Ok(());
+    }
+    let client = Client::new(Config::from_env(year)?);
+    let input = fetch::fetch(&client, day)?;
+    if fetch::install(&path, &input, force)? {
+        println!("wrote {}", path.display());
+    } else {
+        println!("{} is up to date", path.display());
+    }
+    if let Some(key) = Key::find(workspace_root())? {
+        encrypt_inputs(&[dir], &key, &key)?;
+    }
+    Ok(())
+}
+
+/// Encrypts the inputs of every registered day, or rotates them to a new
+/// key with `rotate`.
+fn encrypt(rotate: bool, new_key: Option<String>) -> Result<(), String> {
+    let key = Key::find(workspace_root())?.ok_or_else(|| {
+        format!(
+            "{0} is not set, add a key to .env like {0}={1}",
+            crypt::KEY_VAR,
+            Key::generate().to_hex()
+        )
+    })?;
+    let to = match (rotate, new_key) {
+        (false, _) => key.clone(),
+        (true, Some(hex)) => Key::from_hex(&hex)?,
+        (true, None) => Key::generate(),
+    };
+    // Shown before any input is rewritten, so the new key is not lost if
+    // that fails half way; running the rotation again finishes it
+    if rotate {
+        println!("rotating to {}={}", crypt::KEY_VAR, to.to_hex());
+    }
+    let dirs: Vec<PathBuf> = registry::DAYS.iter().map(Day::dir).collect();
+    encrypt_inputs(&dirs, &key, &to)?;
+    if rotate {
+        println!("set {}={} in .env", crypt::KEY_VAR, to.to_hex());
+    }
+    Ok(())
+}
+
+fn encrypt_inputs(
+    dirs: &[PathBuf],
+    from: &Key,
+    to: &Key,
+) -> Result<(), String> {
+    for outcome in vault::encrypt(dirs, from, to)? {
+        match outcome {
+            vault::Outcome::Written(path) => {
+                println!("wrote {}", path.display())
+            }
+            vault::Outcome::Unchanged(path) => {
+                println!("{} is up to date", path.display())
+            }
+        }
+    }
+    Ok(())
+}
+
+struct VizArgs {
+    format: VizFormat,
+    out: Option<PathBuf>,
+    scale: usize,
+    delay: Duration,
+    frames: usize,
+}
+
+fn visualize(day: &Day, source: &Source, args: VizArgs) -> Result<(), String> {
+    let input = day
+        .load(source)
+        .map_err(|err| format!("cannot load input: {err}"))?;
+    let mut recording = Recording::with_limit(args.frames);
+    day.visualize(&input, &mut recording)
+        .ok_or_else(|| format!("day {} has no visualization", day.number))??;
+
+    let scale = args.scale.max(1);
+    let out = |default: String| args.out.clone().unwrap_or(default.into());
+    let written = match args.format {
+        VizFormat::Ansi => {
+            return recording
+                .replay_ansi(io::stdout().lock(), args.delay)
+                .map_err(|err| err.to_string());
+        }
+        VizFormat::Gif => {
+            let path = out(format!("day{}.gif", day.number));
+            let file = std::fs::File::create(&path)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            recording
+                .write_gif(io::BufWriter::new(file), scale, args.delay)
+                .map_err(|err| format!("{}: {err}", path.display()))?;
+            path.display().to_string()
+        }
+        VizFormat::Ppm | VizFormat::Png => {
+            let format = match args.format {
+                VizFormat::Ppm => ImageFormat::Ppm,
+                _ => ImageFormat::Png,
+            };
+            let dir = out(format!("day{}-frames", day.number));
+            recording
+                .write_images(&dir, format, scale)
+                .map_err(|err| format!("{}: {err}", dir.display()))?;
+            dir.display().to_string()
+        }
+    };
+    println!("wrote {} frames to {written}", recording.frames().len());
+    Ok(())
+}
+
+fn debug(
+    day: &Day,
+    part: Part,
+    at: usize,
+    source: &Source,
+) -> Result<(), String> {
+    let input = day
+        .load(source)
+        .map_err(|err| format!("cannot load input: {err}"))?;
+    let mut tape = Tape::default();
+    day.step_through(&input, part, &mut tape).ok_or_else(|| {
+        format!("day {} cannot be stepped through", day.number)
+    })??;
+
+    let title = format!("{}/day {} part {part}", day.year, day.number);
+    if io::stdout().is_terminal() {
+        debugger::run(&title, &tape, at).map_err(|err| err.to_string())
+    } else {
+        debugger::print(&title, &tape, at);
+        Ok(())
+    }
+}
+
+fn submit_answer(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+) -> Result<(), String> {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let day = find_day(year, day)?;
+            let input = day
+                .load(&Source::Default)
+                .map_err(|err| format!("cannot load input: {err}"))?;
+            day.run(&input, part)?.answer
+        }
+    };
+    let client = Client::new(Config::from_env(year)?);
+    let mut history = submit::History::load(&client)?;
+    println!("{year}/day {day} part {part}: submitting {answer}");
+    let (outcome, message) =
+        submit::submit(&client, &mut history, day, part, &answer)?;
+    println!("{message}");
+
+    let dir = day_dir(workspace_root(), year, day);
+    if outcome == submit::Outcome::Correct && dir.is_dir() {
+        let mut answers = Answers::load(&dir)?;
+        answers.entry(DEFAULT_INPUT).set(part, answer);
+        answers.save(&dir)?;
+    }
+    Ok(())
+}
+
+/// Sends the solvers' logs to stderr, so they never mix with answers. Quiet
+/// unless asked: `-v` shows debug summaries, `-vv` traces every step.
+fn init_logging(verbose: u8) {
+    let level = match verbose {
+        0 => LevelFilter::OFF,
+        1 => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    };
+    tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .init();
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging(cli.verbose);
+    if cli.verbose > 0 {
+        common::progress::enable();
+    }
+    let latest = registry::latest_year().unwrap_or(config::DEFAULT_YEAR);
+    let year = match cli.year.map_or_else(|| config::year(latest), Ok) {
+        Ok(year) => year,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+    let rows = match cli.command {
+        Command::Run {
+            day,
+            part,
+            input,
+            parallel,
+            jobs,
+            format,
+            budget,
+            fresh,
+        } => {
+            let parts = part.map_or(Part::BOTH.to_vec(), |part| vec![part]);
+            let source = input.source();
+            if matches!(day, Selection::All(_)) && input.input.is_some() {
+                eprintln!("--input needs a single day");
+                return ExitCode::from(2);
+            }
+            let selected = day.days(year);
+            let (days, budget) =
+                match selected.and_then(|days| Ok((days, budget.budget()?))) {
+                    Ok(selected) => selected,
+                    Err(err) => {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                };
+            let cache_dir = match Config::from_env(year) {
+                Ok(config) => config.cache_dir,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let cache = ResultCache::new(&cache_dir, fresh);
+            let start = Instant::now();
+            let rows = if parallel {
+                match run_parallel(
+                    &days, &parts, &source, jobs, budget, &cache,
+                ) {
+                    Ok(rows) => rows,
+                    Err(err) => {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            } else {
+                days.iter()
+                    .flat_map(|day| {
+                        run_day(day, &parts, &source, budget, &cache)
+                    })
+                    .collect()
+            };
+            match (format, day) {
+                (Format::Table, Selection::Day(_)) => print_rows(&rows),
+                (Format::Table, Selection::All(_)) => {
+                    print_table(&rows, start.elapsed())
+                }
+                _ => {
+                    if let Err(err) = print_records(&rows, format) {
+                        eprintln!("{err}");
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            rows
+        }
+        Command::Verify {
+            day,
+            record,
+            budget,
+        } => {
+            let days = day.days(year);
+            return match days.and_then(|days| Ok((days, budget.budget()?))) {
+                Ok((days, budget)) => verify(&days, record, budget),
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Bench {
+            day,
+            samples,
+            save,
+            compare,
+            threshold,
+        } => {
+            let args = BenchArgs {
+                samples,
+                save,
+                compare,
+                threshold,
+            };
+            let result = day.days(year).and_then(|days| bench(&days, args));
+            return match result {
+                Ok(true) => ExitCode::SUCCESS,
+                Ok(false) => ExitCode::FAILURE,
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Fetch { day, force } => {
+            let (year, day) = day.resolve(year);
+            if let Err(err) = fetch_input(year, day, force) {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        }
+        Command::Encrypt { rotate, new_key } => {
+            if let Err(err) = encrypt(rotate, new_key) {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        }
+        Command::New { day } => {
+            let (year, day) = day.resolve(year);
+            return match scaffold::scaffold(workspace_root(), year, day) {
+                Ok(written) => {
+                    for path in written {
+                        println!("wrote {}", path.display());
+                    }
+                    println!(
+                        "run `aoc fetch {year}/{day}` to download the input"
+                    );
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("{err}");
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        Command::Viz {
+            day,
+            format,
+            out,
+            scale,
+            delay,
+            frames,
+            input,
+        } => {
+            let args = VizArgs {
+                format,
+                out,
+                scale,
+                delay: Duration::from_millis(delay),
+                frames,
+            };
+            let (year, day) = day.resolve(year);
+            let result = find_day(year, day)
+                .and_then(|day| visualize(day, &input.source(), args));
+            if let Err(err) = result {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        }
+        Command::Debug {
+            day,
+            part,
+            at,
+            input,
+        } => {
+            let (year, day) = day.resolve(year);
+            let result = find_day(year, day)
+                .and_then(|day| debug(day, part, at, &input.source()));
+            if let Err(err) = result {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        }
+        Command::Serve {
+            port,
+            host,
+            workers,
+            budget,
+        } => {
+            let workers = workers
+                .or_else(|| std::thread::available_parallelism().ok())
+                .map_or(1, NonZeroUsize::get);
+            let served = budget.budget().and_then(|budget| {
+                serve::serve(&format!("{host}:{port}"), year, budget, workers)
+            });
+            if let Err(err) = served {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        }
+        Command::Submit { day, part, answer } => {
+            let (year, day) = day.resolve(year);
+            if let Err(err) = submit_answer(year, day, part, answer) {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+            return ExitCode::SUCCESS;
+        }
+    };
+
+    if rows.iter().any(Row::failed) {
+        return ExitCode::FAILURE;
+    }
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_day() {
+        let day = |year, day| DayRef { year, day };
+        assert_eq!(parse_day("16"), Ok(day(None, 16)));
+        assert_eq!(parse_day("day16"), Ok(day(None, 16)));
+        assert_eq!(parse_day("2023/day7"), Ok(day(Some(2023), 7)));
+        assert!(parse_day("2024/26").is_err());
+        assert!(parse_day("1999/1").is_err());
+        assert_eq!(day(None, 16).resolve(2024), (2024, 16));
+        assert_eq!(day(Some(2023), 7).resolve(2024), (2023, 7));
+
+        assert_eq!("all".parse(), Ok(Selection::All(None)));
+        assert_eq!("2024/all".parse(), Ok(Selection::All(Some(2024))));
+        assert_eq!("2024/3".parse(), Ok(Selection::Day(day(Some(2024), 3))));
+        let days = Selection::All(None).days(2024).unwrap();
+        assert_eq!(days.len(), 25);
+        assert!(Selection::All(Some(2015)).days(2024).is_err());
+    }
+}
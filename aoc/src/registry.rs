@@ -0,0 +1,52 @@
+use crate::runner::Day;
+
+pub const DAYS: &[Day] = &[
+    Day::new::<y2024_day1::Day1>(2024, 1, y2024_day1::INPUT),
+    Day::new::<y2024_day2::Day2>(2024, 2, y2024_day2::INPUT),
+    Day::new::<y2024_day3::Day3>(2024, 3, y2024_day3::INPUT),
+    Day::new::<y2024_day4::Day4>(2024, 4, y2024_day4::INPUT),
+    Day::new::<y2024_day5::Day5>(2024, 5, y2024_day5::INPUT),
+    Day::new::<y2024_day6::Day6>(2024, 6, y2024_day6::INPUT)
+        .with_viz::<y2024_day6::Day6>()
+        .with_stepper::<y2024_day6::Day6>(),
+    Day::new::<y2024_day7::Day7>(2024, 7, y2024_day7::INPUT),
+    Day::new::<y2024_day8::Day8>(2024, 8, y2024_day8::INPUT),
+    Day::new::<y2024_day9::Day9>(2024, 9, y2024_day9::INPUT),
+    Day::new::<y2024_day10::Day10>(2024, 10, y2024_day10::INPUT),
+    Day::new::<y2024_day11::Day11>(2024, 11, y2024_day11::INPUT),
+    Day::new::<y2024_day12::Day12>(2024, 12, y2024_day12::INPUT),
+    Day::new::<y2024_day13::Day13>(2024, 13, y2024_day13::INPUT),
+    Day::new::<y2024_day14::Day14>(2024, 14, y2024_day14::INPUT)
+        .with_viz::<y2024_day14::Day14>(),
+    Day::new::<y2024_day15::Day15>(2024, 15, y2024_day15::INPUT)
+        .with_viz::<y2024_day15::Day15>()
+        .with_stepper::<y2024_day15::Day15>(),
+    Day::new::<y2024_day16::Day16>(2024, 16, y2024_day16::INPUT)
+        .with_viz::<y2024_day16::Day16>(),
+    Day::new::<y2024_day17::Day17>(2024, 17, y2024_day17::INPUT)
+        .with_stepper::<y2024_day17::Day17>(),
+    Day::new::<y2024_day18::Day18>(2024, 18, y2024_day18::INPUT)
+        .with_viz::<y2024_day18::Day18>(),
+    Day::new::<y2024_day19::Day19>(2024, 19, y2024_day19::INPUT),
+    Day::new::<y2024_day20::Day20>(2024, 20, y2024_day20::INPUT),
+    Day::new::<y2024_day21::Day21>(2024, 21, y2024_day21::INPUT),
+    Day::new::<y2024_day22::Day22>(2024, 22, y2024_day22::INPUT),
+    Day::new::<y2024_day23::Day23>(2024, 23, y2024_day23::INPUT),
+    Day::new::<y2024_day24::Day24>(2024, 24, y2024_day24::INPUT),
+    Day::new::<y2024_day25::Day25>(2024, 25, y2024_day25::INPUT),
+];
+
+pub fn find(year: u16, number: u8) -> Option<&'static Day> {
+    DAYS.iter()
+        .find(|day| day.year == year && day.number == number)
+}
+
+/// The days solved for `year`, in order.
+pub fn year(year: u16) -> impl Iterator<Item = &'static Day> {
+    DAYS.iter().filter(move |day| day.year == year)
+}
+
+/// The most recent event with a solved day, for days given without a year.
+pub fn latest_year() -> Option<u16> {
+    DAYS.iter().map(|day| day.year).max()
+}
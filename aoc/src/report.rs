@@ -0,0 +1,172 @@
+use crate::answers::Verdict;
+use crate::runner::{Part, Run};
+use clap::ValueEnum;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::io::Write;
+
+/// How `aoc run` prints its results.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Aligned text for people
+    #[default]
+    Table,
+    /// One JSON object per part and line
+    Json,
+    /// A header line and one line per part
+    Csv,
+}
+
+/// One part of a run, as written by `--format json` and `--format csv`.
+/// Times are in nanoseconds; a failed part has an `error` and no answer or
+/// times. The allocation counts are only there with `count-allocs`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    pub total_ns: Option<u64>,
+    pub allocations: Option<u64>,
+    pub allocated_bytes: Option<u64>,
+    pub peak_heap_bytes: Option<u64>,
+    pub input_sha256: Option<String>,
+    pub check: Option<&'static str>,
+    /// Whether the answer and times come from the result cache.
+    pub cached: bool,
+    pub error: Option<String>,
+}
+
+impl Record {
+    pub fn new(
+        year: u16,
+        day: u8,
+        part: Part,
+        outcome: &Result<Run, String>,
+        input_sha256: Option<String>,
+        verdict: Option<&Verdict>,
+    ) -> Self {
+        let nanos = |duration: std::time::Duration| {
+            u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+        };
+        let run = outcome.as_ref().ok();
+        let memory = run.and_then(|run| run.memory);
+        Record {
+            year,
+            day,
+            part: part.number(),
+            answer: run.map(|run| run.answer.clone()),
+            parse_ns: run.map(|run| nanos(run.parse)),
+            solve_ns: run.map(|run| nanos(run.solve)),
+            total_ns: run.map(|run| nanos(run.total())),
+            allocations: memory.map(|memory| memory.allocations),
+            allocated_bytes: memory.map(|memory| memory.bytes),
+            peak_heap_bytes: memory.map(|memory| memory.peak),
+            input_sha256,
+            check: verdict.map(Verdict::label),
+            cached: false,
+            error: outcome.as_ref().err().cloned(),
+        }
+    }
+}
+
+/// Hex SHA-256 of an input, to tell which input a record was produced from
+/// without publishing the input itself.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub fn write_json(
+    mut out: impl Write,
+    records: &[Record],
+) -> Result<(), String> {
+    for record in records {
+        serde_json::to_writer(&mut out, record)
+            .map_err(|err| err.to_string())?;
+        writeln!(out).map_err(|err| err.to_string())?;
+    }
+    Ok(())
+}
+
+pub fn write_csv(out: impl Write, records: &[Record]) -> Result<(), String> {
+    let mut writer = csv::Writer::from_writer(out);
+    for record in records {
+        writer.serialize(record).map_err(|err| err.to_string())?;
+    }
+    writer.flush().map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::alloc::Usage;
+    use indoc::indoc;
+    use std::time::Duration;
+
+    #[test]
+    fn test_write() {
+        let run = Run {
+            answer: "1,2".to_string(),
+            parse: Duration::from_nanos(5),
+            solve: Duration::from_nanos(10),
+            memory: Some(Usage {
+                allocations: 2,
+                bytes: 64,
+                peak: 48,
+            }),
+        };
+        let records = [
+            Record::new(
+                2024,
+                1,
+                Part::One,
+                &Ok(run),
+                None,
+                Some(&Verdict::Pass),
+            ),
+            Record::new(
+                2024,
+                1,
+                Part::Two,
+                &Err("boom".to_string()),
+                None,
+                None,
+            ),
+        ];
+
+        let mut json = vec![];
+        write_json(&mut json, &records).unwrap();
+        let json = String::from_utf8(json).unwrap();
+        let first: serde_json::Value =
+            serde_json::from_str(json.lines().next().unwrap()).unwrap();
+        assert_eq!(first["year"], 2024);
+        assert_eq!(first["answer"], "1,2");
+        assert_eq!(first["total_ns"], 15);
+        assert_eq!(first["check"], "ok");
+        assert_eq!(first["peak_heap_bytes"], 48);
+
+        let mut csv = vec![];
+        write_csv(&mut csv, &records).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            indoc! {r#"
+                year,day,part,answer,parse_ns,solve_ns,total_ns,allocations,allocated_bytes,peak_heap_bytes,input_sha256,check,cached,error
+                2024,1,1,"1,2",5,10,15,2,64,48,,ok,false,
+                2024,1,2,,,,,,,,,,false,boom
+            "#}
+        );
+    }
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
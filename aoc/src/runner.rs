@@ -0,0 +1,448 @@
+use common::alloc::{self, Usage};
+use common::budget::{self, Budget};
+use common::input::Source;
+use common::stepper::{StepThrough, Tape};
+use common::viz::{Recording, Visualize};
+use common::{AocError, Solution};
+use std::any::Any;
+use std::fmt::{Display, Formatter};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("part must be 1 or 2, got {s:?}")),
+        }
+    }
+}
+
+/// Whether the runner counts allocations, with the `count-allocs` feature.
+pub const COUNTS_ALLOCATIONS: bool = cfg!(feature = "count-allocs");
+
+/// Answer of one part with the wall-clock time spent parsing and solving,
+/// and with `count-allocs` what parsing and solving allocated.
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answer: String,
+    pub parse: Duration,
+    pub solve: Duration,
+    pub memory: Option<Usage>,
+}
+
+impl Run {
+    pub fn total(&self) -> Duration {
+        self.parse + self.solve
+    }
+}
+
+/// How long a part over its time budget gets to notice the cancellation
+/// before the runner stops waiting for it.
+const GRACE: Duration = Duration::from_secs(1);
+
+/// A day crate as the runner sees it, with its `Solution` types erased.
+pub struct Day {
+    pub year: u16,
+    pub number: u8,
+    embedded: Option<&'static str>,
+    run: fn(&str, Part) -> Result<Run, AocError>,
+    visualize: Option<VisualizeFn>,
+    step_through: Option<StepThroughFn>,
+}
+
+type VisualizeFn = fn(&str, &mut Recording) -> Result<(), AocError>;
+type StepThroughFn = fn(&str, Part, &mut Tape) -> Result<(), AocError>;
+
+impl Day {
+    pub const fn new<S: Solution>(
+        year: u16,
+        number: u8,
+        embedded: Option<&'static str>,
+    ) -> Self {
+        Day {
+            year,
+            number,
+            embedded,
+            run: run::<S>,
+            visualize: None,
+            step_through: None,
+        }
+    }
+
+    /// Lets `aoc viz` record the day's simulation.
+    pub const fn with_viz<S: Visualize>(self) -> Self {
+        Day {
+            visualize: Some(visualize::<S>),
+            ..self
+        }
+    }
+
+    /// Lets `aoc debug` step through the day's simulation.
+    pub const fn with_stepper<S: StepThrough>(self) -> Self {
+        Day {
+            step_through: Some(step_through::<S>),
+            ..self
+        }
+    }
+
+    pub fn dir(&self) -> PathBuf {
+        day_dir(workspace_root(), self.year, self.number)
+    }
+
+    pub fn load(&self, source: &Source) -> io::Result<String> {
+        source.load(&self.dir(), self.embedded)
+    }
+
+    /// Runs one part. Errors, rendered against the input, and panics both
+    /// come back as an error message.
+    pub fn run(&self, input: &str, part: Part) -> Result<Run, String> {
+        panic::catch_unwind(AssertUnwindSafe(|| (self.run)(input, part)))
+            .map_err(panic_message)?
+            .map_err(|err| err.render(input))
+    }
+
+    /// Runs one part under `budget`. A part with a time budget runs on its
+    /// own thread: once over, its next `budget::check` fails, and if it
+    /// does not stop within a grace period the runner reports the timeout
+    /// anyway and leaves the thread behind. An answer that comes late, or
+    /// after holding more heap than allowed, fails too.
+    pub fn run_within(
+        &self,
+        input: &str,
+        part: Part,
+        budget: Budget,
+    ) -> Result<Run, String> {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let Some(time) = budget.time else {
+            let outcome =
+                budget::within(budget, cancelled, || self.run(input, part));
+            return within_memory(outcome, budget);
+        };
+        let (sender, receiver) = mpsc::channel();
+        let run = self.run;
+        let input = input.to_string();
+        let flag = Arc::clone(&cancelled);
+        thread::Builder::new()
+            .name(format!("{}/{} part {part}", self.year, self.number))
+            // As much stack as the main thread, for recursive solvers
+            .stack_size(8 << 20)
+            .spawn(move || {
+                let outcome = panic::catch_unwind(AssertUnwindSafe(|| {
+                    budget::within(budget, flag, || run(&input, part))
+                }))
+                .map_err(panic_message)
+                .and_then(|run| run.map_err(|err| err.render(&input)));
+                // The runner may have stopped waiting
+                let _ = sender.send(outcome);
+            })
+            .map_err(|err| format!("cannot start the part: {err}"))?;
+        let start = Instant::now();
+        match receiver.recv_timeout(time + GRACE) {
+            Ok(_) if start.elapsed() > time => Err(Budget::timed_out(time)),
+            Ok(outcome) => within_memory(outcome, budget),
+            Err(mpsc::RecvTimeoutError::Timeout) => {
+                cancelled.store(true, Ordering::Relaxed);
+                Err(Budget::timed_out(time))
+            }
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Err("the part stopped without an answer".to_string())
+            }
+        }
+    }
+
+    /// Records the day's simulation, or `None` if it has none.
+    pub fn visualize(
+        &self,
+        input: &str,
+        recording: &mut Recording,
+    ) -> Option<Result<(), String>> {
+        let visualize = self.visualize?;
+        Some(catch(input, || visualize(input, recording)))
+    }
+
+    /// Records every step of `part`, or `None` if the day cannot be
+    /// stepped through.
+    pub fn step_through(
+        &self,
+        input: &str,
+        part: Part,
+        tape: &mut Tape,
+    ) -> Option<Result<(), String>> {
+        let step_through = self.step_through?;
+        Some(catch(input, || step_through(input, part, tape)))
+    }
+}
+
+/// Fails a run that held more heap than `budget` allows at some point
+/// between two checks.
+fn within_memory(
+    outcome: Result<Run, String>,
+    budget: Budget,
+) -> Result<Run, String> {
+    let usage = outcome.as_ref().ok().and_then(|run| run.memory);
+    match (usage, budget.memory) {
+        (Some(usage), Some(memory)) if usage.peak > memory => {
+            Err(Budget::over_memory(memory))
+        }
+        _ => outcome,
+    }
+}
+
+/// Runs `f`, turning an error or a panic into a message.
+fn catch(
+    input: &str,
+    f: impl FnOnce() -> Result<(), AocError>,
+) -> Result<(), String> {
+    panic::catch_unwind(AssertUnwindSafe(f))
+        .map_err(panic_message)?
+        .map_err(|err| err.render(input))
+}
+
+fn step_through<S: StepThrough>(
+    input: &str,
+    part: Part,
+    tape: &mut Tape,
+) -> Result<(), AocError> {
+    S::step_through(&S::parse(input)?, part.number(), tape)
+}
+
+fn visualize<S: Visualize>(
+    input: &str,
+    recording: &mut Recording,
+) -> Result<(), AocError> {
+    S::visualize(&S::parse(input)?, recording)
+}
+
+fn run<S: Solution>(input: &str, part: Part) -> Result<Run, AocError> {
+    let (run, usage) = alloc::measure(|| timed::<S>(input, part));
+    Ok(Run {
+        memory: COUNTS_ALLOCATIONS.then_some(usage),
+        ..run?
+    })
+}
+
+fn timed<S: Solution>(input: &str, part: Part) -> Result<Run, AocError> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse = start.elapsed();
+
+    let start = Instant::now();
+    let answer = match part {
+        Part::One => S::part1(&parsed)?.to_string(),
+        Part::Two => S::part2(&parsed)?.to_string(),
+    };
+    Ok(Run {
+        answer,
+        parse,
+        solve: start.elapsed(),
+        memory: None,
+    })
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    "solver panicked".to_string()
+}
+
+/// Where the crate of a day lives, like `2024/day16`.
+pub fn day_dir(root: &Path, year: u16, day: u8) -> PathBuf {
+    root.join(year.to_string()).join(format!("day{day}"))
+}
+
+pub fn workspace_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..1_000 => format!("{nanos}ns"),
+        1_000..1_000_000 => format!("{:.1}µs", nanos as f64 / 1e3),
+        1_000_000..1_000_000_000 => format!("{:.1}ms", nanos as f64 / 1e6),
+        _ => format!("{:.2}s", nanos as f64 / 1e9),
+    }
+}
+
+/// Peak resident memory of the process so far, where the platform tells.
+pub fn peak_memory() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib * 1024)
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes}B"),
+        1024..1_048_576 => format!("{:.1}KiB", bytes as f64 / 1024.0),
+        1_048_576..1_073_741_824 => {
+            format!("{:.1}MiB", bytes as f64 / 1_048_576.0)
+        }
+        _ => format!("{:.1}GiB", bytes as f64 / 1_073_741_824.0),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(999)), "999ns");
+        assert_eq!(format_duration(Duration::from_micros(1500)), "1.5ms");
+        assert_eq!(format_duration(Duration::from_millis(2500)), "2.50s");
+        assert_eq!(format_bytes(512), "512B");
+        assert_eq!(format_bytes(3 * 1_048_576), "3.0MiB");
+        assert_eq!(format_bytes(5 * 1_073_741_824), "5.0GiB");
+    }
+
+    #[test]
+    fn test_errors() {
+        struct Broken;
+
+        impl Solution for Broken {
+            type Input<'a> = &'a str;
+            type Part1 = usize;
+            type Part2 = usize;
+
+            fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+                match input.find('!') {
+                    Some(i) => Err(AocError::at(&input[i..], "no shouting")),
+                    None => Ok(input),
+                }
+            }
+
+            fn part1(input: &Self::Input<'_>) -> Result<usize, AocError> {
+                Ok(input.len())
+            }
+
+            fn part2(_input: &Self::Input<'_>) -> Result<usize, AocError> {
+                panic!("disco!")
+            }
+        }
+
+        let day = Day::new::<Broken>(2024, 0, None);
+        assert_eq!(day.run("abc", Part::One).unwrap().answer, "3");
+        assert_eq!(day.run("abc", Part::Two).unwrap_err(), "disco!");
+        let err = day.run("ab\nc!", Part::One).unwrap_err();
+        assert!(err.starts_with("line 2, column 2: no shouting"));
+    }
+
+    #[test]
+    fn test_run_within() {
+        struct Slow;
+
+        impl Solution for Slow {
+            type Input<'a> = u64;
+            type Part1 = usize;
+            type Part2 = usize;
+
+            fn parse(input: &str) -> Result<u64, AocError> {
+                common::parse::number(input)
+            }
+
+            fn part1(millis: &u64) -> Result<usize, AocError> {
+                let start = Instant::now();
+                while start.elapsed() < Duration::from_millis(*millis) {
+                    budget::check()?;
+                }
+                Ok(1)
+            }
+
+            /// Never checks its budget.
+            fn part2(millis: &u64) -> Result<usize, AocError> {
+                thread::sleep(Duration::from_millis(*millis));
+                Ok(2)
+            }
+        }
+
+        let day = Day::new::<Slow>(2024, 0, None);
+        let budget = Budget {
+            time: Some(Duration::from_millis(50)),
+            memory: None,
+        };
+        let run = |input, part| day.run_within(input, part, budget);
+        assert_eq!(run("0", Part::One).unwrap().answer, "1");
+        assert_eq!(run("0", Part::Two).unwrap().answer, "2");
+        assert_eq!(
+            run("5000", Part::One).unwrap_err(),
+            "timed out after 50ms"
+        );
+        let start = Instant::now();
+        assert_eq!(
+            run("3000", Part::Two).unwrap_err(),
+            "timed out after 50ms"
+        );
+        assert!(start.elapsed() < Duration::from_secs(2));
+    }
+
+    #[test]
+    fn test_runaway_program() {
+        let day = crate::registry::DAYS
+            .iter()
+            .find(|day| (day.year, day.number) == (2024, 17))
+            .unwrap();
+        // Prints A and jumps back to the start for as long as A is not zero
+        let input = indoc! {"
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 5,4,3,0
+        "};
+        let time = Duration::from_millis(100);
+        let budget = Budget {
+            time: Some(time),
+            memory: None,
+        };
+        let start = Instant::now();
+        let err = day.run_within(input, Part::One, budget).unwrap_err();
+        assert_eq!(err, "timed out after 100ms");
+        // The program stopped itself rather than being left behind
+        assert!(start.elapsed() < time + GRACE);
+    }
+}
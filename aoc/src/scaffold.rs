@@ -0,0 +1,406 @@
+use crate::runner::day_dir;
+use indoc::{formatdoc, indoc};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+const TEMPLATE: &str = "daily-template";
+
+/// Files copied from the template, relative to the crate directory.
+const FILES: &[&str] = &[
+    "Cargo.toml",
+    "src/lib.rs",
+    "src/main.rs",
+    "src/generate.rs",
+    "src/reference.rs",
+    "benches/solution.rs",
+    "examples/example.txt",
+];
+
+/// The `cargo fuzz` target of a day, in `fuzz/fuzz_targets/YEAR/dayN.rs`.
+const FUZZ_TARGET: &str = indoc! {"
+    #![no_main]
+
+    use libfuzzer_sys::fuzz_target;
+
+    fuzz_target!(|input: &str| {
+        common::fuzz::solve::<{{crate_name}}::{{struct_name}}>(input);
+    });
+"};
+
+/// Creates `YEAR/dayN` from the template and registers it with the runner:
+/// a dependency and an `embed-inputs` entry in `aoc/Cargo.toml`, and a line
+/// in `aoc/src/registry.rs`. It also gets a fuzz target, with a dependency
+/// and a `[[bin]]` in `fuzz/Cargo.toml`. Returns the files written.
+pub fn scaffold(
+    root: &Path,
+    year: u16,
+    day: u8,
+) -> Result<Vec<PathBuf>, String> {
+    let dir = day_dir(root, year, day);
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let manifest_path = root.join("aoc").join("Cargo.toml");
+    let registry_path = root.join("aoc").join("src").join("registry.rs");
+    let manifest = register_manifest(&read(&manifest_path)?, year, day)?;
+    let registry = register_day(&read(&registry_path)?, year, day)?;
+    let fuzz_manifest_path = root.join("fuzz").join("Cargo.toml");
+    let fuzz_manifest =
+        register_fuzz_target(&read(&fuzz_manifest_path)?, year, day)?;
+
+    let mut written = vec![];
+    for file in FILES {
+        let template = read(&root.join(TEMPLATE).join(file))?;
+        let path = dir.join(file);
+        write(&path, &render(&template, year, day))?;
+        written.push(path);
+    }
+    let fuzz_target_path = root
+        .join("fuzz")
+        .join("fuzz_targets")
+        .join(year.to_string())
+        .join(format!("day{day}.rs"));
+    write(&fuzz_target_path, &render(FUZZ_TARGET, year, day))?;
+    write(&manifest_path, &manifest)?;
+    write(&registry_path, &registry)?;
+    write(&fuzz_manifest_path, &fuzz_manifest)?;
+    written.extend([
+        fuzz_target_path,
+        manifest_path,
+        registry_path,
+        fuzz_manifest_path,
+    ]);
+    Ok(written)
+}
+
+/// Fills in the template placeholders: `{{package_name}}` is `y2024-day7`,
+/// `{{crate_name}}` is `y2024_day7` and `{{struct_name}}` is `Day7`.
+fn render(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{struct_name}}", &format!("Day{day}"))
+        .replace("{{package_name}}", &format!("y{year}-day{day}"))
+        .replace("{{crate_name}}", &format!("y{year}_day{day}"))
+}
+
+type DayKey = (u16, u8);
+
+fn register_manifest(
+    manifest: &str,
+    year: u16,
+    day: u8,
+) -> Result<String, String> {
+    let package = format!("y{year}-day{day}");
+    let manifest = insert_sorted(
+        manifest,
+        (year, day),
+        &format!("{package} = {{ path = \"../{year}/day{day}\" }}"),
+        |line| {
+            let (key, rest) = leading_day(line, "-day")?;
+            rest.starts_with(" = { path").then_some(key)
+        },
+    )?;
+    insert_sorted(
+        &manifest,
+        (year, day),
+        &format!("    \"{package}/embed-input\","),
+        |line| {
+            let line = line.trim().strip_prefix('"')?;
+            let (key, rest) = leading_day(line, "-day")?;
+            rest.starts_with("/embed-input").then_some(key)
+        },
+    )
+}
+
+fn register_day(registry: &str, year: u16, day: u8) -> Result<String, String> {
+    // An entry goes on over the lines of its `.with_viz::<…>()` and such up
+    // to the `,` that ends it, and moves as one.
+    let mut entries: Vec<String> = vec![];
+    let mut open = false;
+    for line in registry.lines() {
+        match entries.last_mut() {
+            Some(entry) if open => {
+                entry.push('\n');
+                entry.push_str(line);
+            }
+            _ => entries.push(line.to_string()),
+        }
+        open = (open || line.trim_start().starts_with("Day::new::<"))
+            && !line.trim_end().ends_with(',');
+    }
+    let krate = format!("y{year}_day{day}");
+    let entry = format!(
+        "    Day::new::<{krate}::Day{day}>({year}, {day}, {krate}::INPUT),"
+    );
+    let entries = insert_among(
+        entries.iter().map(String::as_str).collect(),
+        (year, day),
+        &entry,
+        |entry| {
+            let entry = entry.trim().strip_prefix("Day::new::<")?;
+            leading_day(entry, "_day").map(|(key, _)| key)
+        },
+    )?;
+    Ok(entries.join("\n") + "\n")
+}
+
+fn register_fuzz_target(
+    manifest: &str,
+    year: u16,
+    day: u8,
+) -> Result<String, String> {
+    let package = format!("y{year}-day{day}");
+    let manifest = insert_sorted(
+        manifest,
+        (year, day),
+        &format!("{package} = {{ path = \"../{year}/day{day}\" }}"),
+        |line| {
+            let (key, rest) = leading_day(line, "-day")?;
+            rest.starts_with(" = { path").then_some(key)
+        },
+    )?;
+    // The targets are blocks of lines apart from each other
+    let blocks: Vec<&str> = manifest.trim_end().split("\n\n").collect();
+    let target = formatdoc! {r#"
+        [[bin]]
+        name = "{package}"
+        path = "fuzz_targets/{year}/day{day}.rs"
+        test = false
+        doc = false
+        bench = false"#
+    };
+    let blocks = insert_among(blocks, (year, day), &target, |block| {
+        let name = block.strip_prefix("[[bin]]\nname = \"")?;
+        let (key, rest) = leading_day(name, "-day")?;
+        rest.starts_with('"').then_some(key)
+    })?;
+    Ok(blocks.join("\n\n") + "\n")
+}
+
+/// The year and day of a crate name like `y2024-day7` at the start of `s`,
+/// with `separator` between them, and what follows the name.
+fn leading_day<'a>(s: &'a str, separator: &str) -> Option<(DayKey, &'a str)> {
+    let (year, rest) = leading_number(s.strip_prefix('y')?)?;
+    let (day, rest) = leading_number(rest.strip_prefix(separator)?)?;
+    Some(((year, day), rest))
+}
+
+/// The number at the start of `s`, and what follows it.
+fn leading_number<T: FromStr>(s: &str) -> Option<(T, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    Some((s[..end].parse().ok()?, &s[end..]))
+}
+
+/// Inserts `line` among the lines `day_of` gives a year and day for,
+/// keeping them ordered by year and day.
+fn insert_sorted(
+    text: &str,
+    day: DayKey,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<DayKey>,
+) -> Result<String, String> {
+    let lines = insert_among(text.lines().collect(), day, line, day_of)?;
+    Ok(lines.join("\n") + "\n")
+}
+
+/// Inserts `item` among the `items` `day_of` gives a year and day for,
+/// keeping them ordered by year and day.
+fn insert_among<'a>(
+    mut items: Vec<&'a str>,
+    day: DayKey,
+    item: &'a str,
+    day_of: impl Fn(&str) -> Option<DayKey>,
+) -> Result<Vec<&'a str>, String> {
+    let days: Vec<(usize, DayKey)> = items
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|key| (i, key)))
+        .collect();
+    if days.iter().any(|&(_, key)| key == day) {
+        let (year, day) = day;
+        return Err(format!("{year} day {day} is already registered"));
+    }
+    let at = days
+        .iter()
+        .find(|&&(_, key)| key > day)
+        .or(days.last())
+        .map(|&(i, key)| if key > day { i } else { i + 1 })
+        .ok_or_else(|| {
+            let (year, day) = day;
+            format!("no day entries to add {year} day {day} next to")
+        })?;
+    items.insert(at, item);
+    Ok(items)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path)
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::create_dir_all(path.parent().expect("files live in a directory"))
+        .and_then(|_| fs::write(path, content))
+        .map_err(|err| format!("{}: {err}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::workspace_root;
+    use indoc::indoc;
+    use std::env;
+
+    #[test]
+    fn test_scaffold() {
+        let root = env::temp_dir().join("aoc-scaffold");
+        let _ = fs::remove_dir_all(&root);
+        for file in FILES {
+            let template = workspace_root().join(TEMPLATE).join(file);
+            write(&root.join(TEMPLATE).join(file), &read(&template).unwrap())
+                .unwrap();
+        }
+        let manifest = indoc! {r#"
+            [dependencies]
+            common = { path = "../common" }
+            y2024-day1 = { path = "../2024/day1" }
+            y2024-day3 = { path = "../2024/day3" }
+
+            [features]
+            embed-inputs = [
+                "y2024-day1/embed-input",
+                "y2024-day3/embed-input",
+            ]
+        "#};
+        let registry = indoc! {"
+            pub const DAYS: &[Day] = &[
+                Day::new::<y2024_day1::Day1>(2024, 1, y2024_day1::INPUT),
+                Day::new::<y2024_day3::Day3>(2024, 3, y2024_day3::INPUT),
+            ];
+        "};
+        write(&root.join("aoc/Cargo.toml"), manifest).unwrap();
+        write(&root.join("aoc/src/registry.rs"), registry).unwrap();
+        let fuzz_manifest = indoc! {r#"
+            [dependencies]
+            libfuzzer-sys = "0.4"
+            common = { path = "../common" }
+            y2024-day1 = { path = "../2024/day1" }
+            y2024-day3 = { path = "../2024/day3" }
+
+            [[bin]]
+            name = "y2024-day1"
+            path = "fuzz_targets/2024/day1.rs"
+            test = false
+            doc = false
+            bench = false
+
+            [[bin]]
+            name = "y2024-day3"
+            path = "fuzz_targets/2024/day3.rs"
+            test = false
+            doc = false
+            bench = false
+        "#};
+        write(&root.join("fuzz/Cargo.toml"), fuzz_manifest).unwrap();
+
+        scaffold(&root, 2024, 2).unwrap();
+        let main = read(&root.join("2024/day2/src/main.rs")).unwrap();
+        assert!(main.starts_with("use y2024_day2::{Day2, INPUT};"));
+        let lib = read(&root.join("2024/day2/src/lib.rs")).unwrap();
+        assert!(lib.contains("impl Solution for Day2 {"));
+        assert!(!lib.contains("{{"));
+        let generate = read(&root.join("2024/day2/src/generate.rs")).unwrap();
+        assert!(generate.contains("pub fn input("));
+        let reference =
+            read(&root.join("2024/day2/src/reference.rs")).unwrap();
+        assert!(reference.contains("pub fn expected("));
+        assert!(lib.contains("#[cfg(test)]\nmod generate;"));
+        assert!(lib.contains("common::generator_tests! {\n        Day2;"));
+        let cargo = read(&root.join("2024/day2/Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"y2024-day2\""));
+        let manifest = read(&root.join("aoc/Cargo.toml")).unwrap();
+        assert!(manifest.contains(indoc! {r#"
+            y2024-day1 = { path = "../2024/day1" }
+            y2024-day2 = { path = "../2024/day2" }
+            y2024-day3 = { path = "../2024/day3" }
+        "#}));
+        assert!(
+            manifest.contains("\"y2024-day2/embed-input\",\n    \"y2024-day3")
+        );
+        let registry = read(&root.join("aoc/src/registry.rs")).unwrap();
+        assert!(registry.contains(indoc! {"
+                Day::new::<y2024_day2::Day2>(2024, 2, y2024_day2::INPUT),
+                Day::new::<y2024_day3::Day3>(2024, 3, y2024_day3::INPUT),
+            ];
+        "}));
+
+        let target =
+            read(&root.join("fuzz/fuzz_targets/2024/day2.rs")).unwrap();
+        assert!(
+            target.contains("common::fuzz::solve::<y2024_day2::Day2>(input);")
+        );
+        let fuzz_manifest = read(&root.join("fuzz/Cargo.toml")).unwrap();
+        assert!(fuzz_manifest.contains(indoc! {r#"
+            y2024-day1 = { path = "../2024/day1" }
+            y2024-day2 = { path = "../2024/day2" }
+            y2024-day3 = { path = "../2024/day3" }
+        "#}));
+        assert!(fuzz_manifest.contains(indoc! {r#"
+            bench = false
+
+            [[bin]]
+            name = "y2024-day2"
+            path = "fuzz_targets/2024/day2.rs"
+            test = false
+            doc = false
+            bench = false
+
+            [[bin]]
+            name = "y2024-day3"
+        "#}));
+
+        // An earlier event goes before every day of a later one
+        scaffold(&root, 2023, 25).unwrap();
+        let registry = read(&root.join("aoc/src/registry.rs")).unwrap();
+        assert!(registry.contains(indoc! {"
+            pub const DAYS: &[Day] = &[
+                Day::new::<y2023_day25::Day25>(2023, 25, y2023_day25::INPUT),
+                Day::new::<y2024_day1::Day1>(2024, 1, y2024_day1::INPUT),
+        "}));
+
+        assert!(scaffold(&root, 2024, 2).is_err());
+        fs::remove_dir_all(root.join("2024/day2")).unwrap();
+        assert!(scaffold(&root, 2024, 2)
+            .unwrap_err()
+            .contains("already registered"));
+    }
+
+    #[test]
+    fn test_register_day_after_chained_entry() {
+        let registry = indoc! {"
+            pub const DAYS: &[Day] = &[
+                Day::new::<y2024_day1::Day1>(2024, 1, y2024_day1::INPUT)
+                    .with_stepper::<y2024_day1::Day1>(),
+                Day::new::<y2024_day3::Day3>(2024, 3, y2024_day3::INPUT)
+                    .with_viz::<y2024_day3::Day3>()
+                    .with_stepper::<y2024_day3::Day3>(),
+            ];
+        "};
+        let registry = register_day(registry, 2024, 4).unwrap();
+        let registry = register_day(&registry, 2024, 2).unwrap();
+        assert_eq!(
+            registry,
+            indoc! {"
+                pub const DAYS: &[Day] = &[
+                    Day::new::<y2024_day1::Day1>(2024, 1, y2024_day1::INPUT)
+                        .with_stepper::<y2024_day1::Day1>(),
+                    Day::new::<y2024_day2::Day2>(2024, 2, y2024_day2::INPUT),
+                    Day::new::<y2024_day3::Day3>(2024, 3, y2024_day3::INPUT)
+                        .with_viz::<y2024_day3::Day3>()
+                        .with_stepper::<y2024_day3::Day3>(),
+                    Day::new::<y2024_day4::Day4>(2024, 4, y2024_day4::INPUT),
+                ];
+            "}
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use crate::registry;
+use crate::report::{self, Record};
+use crate::runner::Part;
+use crate::{find_day, parse_day};
+use common::budget::Budget;
+use serde::Serialize;
+use serde_json::json;
+use std::io::Read;
+use std::sync::mpsc::{self, TrySendError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+/// The page to paste an input into, served at `/`.
+const PAGE: &str = include_str!("serve.html");
+/// Largest input accepted; real ones are a few dozen KiB.
+const MAX_INPUT: u64 = 1 << 20;
+
+/// A response before it is sent.
+#[derive(Debug, PartialEq)]
+struct Reply {
+    status: u16,
+    content_type: &'static str,
+    body: String,
+}
+
+impl Reply {
+    fn json(status: u16, body: &impl Serialize) -> Self {
+        Reply {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_string(body).expect("replies serialize"),
+        }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        Reply::json(status, &json!({ "error": message.into() }))
+    }
+}
+
+/// Serves the solvers on `addr` until the process is stopped, with
+/// `workers` requests handled at once. Days given without a year are taken
+/// from `year`.
+pub fn serve(
+    addr: &str,
+    year: u16,
+    budget: Budget,
+    workers: usize,
+) -> Result<(), String> {
+    if budget.time.is_none() {
+        return Err("serving needs a time budget above 0".to_string());
+    }
+    let server = Server::http(addr)
+        .map_err(|err| format!("cannot listen on {addr}: {err}"))?;
+    println!("serving the solvers on http://{addr}/");
+    listen(&server, year, budget, workers);
+    Ok(())
+}
+
+/// Hands requests to `workers` threads, with as many more waiting, and
+/// answers 503 to the rest. A part that timed out frees its worker with
+/// the reply, even if its thread has not stopped yet.
+fn listen(server: &Server, year: u16, budget: Budget, workers: usize) {
+    let (sender, receiver) = mpsc::sync_channel::<Request>(workers);
+    let receiver = Arc::new(Mutex::new(receiver));
+    for _ in 0..workers {
+        let receiver = Arc::clone(&receiver);
+        thread::spawn(move || loop {
+            let request = receiver.lock().map(|receiver| receiver.recv());
+            match request {
+                Ok(Ok(request)) => respond(request, year, budget),
+                _ => return,
+            }
+        });
+    }
+    for request in server.incoming_requests() {
+        if let Err(TrySendError::Full(request)) = sender.try_send(request) {
+            send(request, busy());
+        }
+    }
+}
+
+fn respond(mut request: Request, year: u16, budget: Budget) {
+    let mut body = vec![];
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT + 1)
+        .read_to_end(&mut body);
+    let reply = match read {
+        Err(err) => Reply::error(400, format!("cannot read the body: {err}")),
+        Ok(_) if body.len() as u64 > MAX_INPUT => Reply::error(
+            413,
+            format!("inputs are limited to {MAX_INPUT} bytes"),
+        ),
+        Ok(_) => route(request.method(), request.url(), &body, year, budget),
+    };
+    send(request, reply);
+}
+
+fn busy() -> Reply {
+    Reply::error(503, "busy, try again in a moment")
+}
+
+fn send(request: Request, reply: Reply) {
+    let content_type = Header::from_bytes("Content-Type", reply.content_type)
+        .expect("the header is valid");
+    let response = Response::from_string(reply.body)
+        .with_status_code(reply.status)
+        .with_header(content_type);
+    // The client may be gone already
+    let _ = request.respond(response);
+}
+
+fn route(
+    method: &Method,
+    url: &str,
+    body: &[u8],
+    year: u16,
+    budget: Budget,
+) -> Reply {
+    match (method, url) {
+        (Method::Get, "/") => Reply {
+            status: 200,
+            content_type: "text/html; charset=utf-8",
+            body: PAGE.to_string(),
+        },
+        (Method::Get, "/days") => {
+            let days: Vec<_> = registry::DAYS
+                .iter()
+                .map(|day| json!({ "year": day.year, "day": day.number }))
+                .collect();
+            Reply::json(200, &days)
+        }
+        (Method::Post, url) if url.starts_with("/solve/") => {
+            solve(&url["/solve/".len()..], body, year, budget)
+        }
+        (_, "/" | "/days") => Reply::error(405, "use GET"),
+        (_, url) if url.starts_with("/solve/") => {
+            Reply::error(405, "use POST with the input as the body")
+        }
+        _ => Reply::error(404, format!("no route {url}")),
+    }
+}
+
+/// Solves `day/part`, where the day may carry a year like `2024/16/1`.
+fn solve(path: &str, body: &[u8], year: u16, budget: Budget) -> Reply {
+    let Some((day, part)) = path.rsplit_once('/') else {
+        return Reply::error(404, "expected /solve/{day}/{part}");
+    };
+    let part: Part = match part.parse() {
+        Ok(part) => part,
+        Err(err) => return Reply::error(404, err),
+    };
+    let day = match parse_day(day).and_then(|day| {
+        let (year, day) = day.resolve(year);
+        find_day(year, day)
+    }) {
+        Ok(day) => day,
+        Err(err) => return Reply::error(404, err),
+    };
+    let Ok(input) = std::str::from_utf8(body) else {
+        return Reply::error(400, "the input is not UTF-8");
+    };
+
+    let outcome = day.run_within(input, part, budget);
+    let status = if outcome.is_ok() { 200 } else { 422 };
+    let hash = Some(report::input_hash(input));
+    let record = Record::new(day.year, day.number, part, &outcome, hash, None);
+    Reply::json(status, &record)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+    use std::io::Write;
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::Duration;
+
+    #[test]
+    fn test_route() {
+        let budget = Budget::default();
+        let route = |method, url, body: &str| {
+            route(&method, url, body.as_bytes(), 2024, budget)
+        };
+        let input = indoc! {"
+            3   4
+            4   3
+            2   5
+            1   3
+            3   9
+            3   3
+        "};
+
+        let solved = route(Method::Post, "/solve/1/1", input);
+        assert_eq!(solved.status, 200);
+        let record: serde_json::Value =
+            serde_json::from_str(&solved.body).unwrap();
+        assert_eq!(record["answer"], "11");
+        assert!(record["solve_ns"].is_u64());
+        let solved = route(Method::Post, "/solve/2024/day1/2", input);
+        assert!(solved.body.contains(r#""answer":"31""#));
+
+        let failed = route(Method::Post, "/solve/1/1", "3 x");
+        assert_eq!(failed.status, 422);
+        assert!(failed.body.contains("line 1, column 3"));
+        assert_eq!(route(Method::Post, "/solve/26/1", input).status, 404);
+        assert_eq!(route(Method::Post, "/solve/1/3", input).status, 404);
+        assert_eq!(route(Method::Get, "/solve/1/1", "").status, 405);
+        assert!(route(Method::Get, "/", "").body.contains("<textarea"));
+        assert_eq!(route(Method::Get, "/nope", "").status, 404);
+    }
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST {path} HTTP/1.1\r\nContent-Length: {}\r\n\
+             Connection: close\r\n\r\n{body}",
+            body.len()
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_runaway() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        let budget = Budget {
+            time: Some(Duration::from_millis(200)),
+            memory: None,
+        };
+        let workers = 8;
+        thread::spawn(move || listen(&server, 2024, budget, workers));
+        let runaway = indoc! {"
+            Register A: 1
+            Register B: 0
+            Register C: 0
+
+            Program: 5,4,3,0
+        "};
+
+        // Parts left running after their reply would leave no worker free
+        for _ in 0..workers {
+            let reply = post(addr, "/solve/17/1", runaway);
+            assert!(reply.starts_with("HTTP/1.1 422"), "{reply}");
+            assert!(reply.contains("timed out after 200ms"));
+        }
+        let reply = post(addr, "/solve/1/1", "3   4\n");
+        assert!(reply.starts_with("HTTP/1.1 200"), "{reply}");
+    }
+
+    #[test]
+    fn test_abandoned() {
+        let server = Server::http("127.0.0.1:0").unwrap();
+        let addr = server.server_addr().to_ip().unwrap();
+        let budget = Budget {
+            time: Some(Duration::from_millis(100)),
+            memory: None,
+        };
+        let workers = 2;
+        thread::spawn(move || listen(&server, 2024, budget, workers));
+        // Day 9 never checks its budget, and this disk takes it seconds
+        let disk = "91".repeat(40_000);
+
+        // The parts keep running after their reply, but not on a worker
+        for _ in 0..workers {
+            let reply = post(addr, "/solve/9/2", &disk);
+            assert!(reply.starts_with("HTTP/1.1 422"), "{reply}");
+            assert!(reply.contains("timed out after 100ms"));
+        }
+        let reply = post(addr, "/solve/1/1", "3   4\n");
+        assert!(reply.starts_with("HTTP/1.1 200"), "{reply}");
+    }
+}
@@ -0,0 +1,103 @@
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Files of a day crate that are not part of its solver.
+const NOT_SOURCE: &[&str] = &["input.txt", "input.txt.enc", "target"];
+
+/// The sources the answers depend on: `src/` and `Cargo.toml` of each of
+/// `crates`, and the workspace `Cargo.toml` and `Cargo.lock` in `root`, in
+/// a stable order. Inputs, answers, examples and benches are left out, so
+/// editing them keeps the cached runs.
+pub fn files(root: &Path, crates: &[PathBuf]) -> io::Result<Vec<PathBuf>> {
+    let mut files = vec![root.join("Cargo.toml"), root.join("Cargo.lock")];
+    for dir in crates {
+        files.push(dir.join("Cargo.toml"));
+        source_files(&dir.join("src"), &mut files)?;
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Hex SHA-256 over the names, relative to `root`, and contents of `files`.
+pub fn hash(root: &Path, files: &[PathBuf]) -> io::Result<String> {
+    let mut hasher = Sha256::new();
+    for file in files {
+        let name = file.strip_prefix(root).unwrap_or(file);
+        hasher.update(name.to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(file)?);
+        hasher.update([0]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect())
+}
+
+fn source_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        let name = path.file_name().unwrap_or_default();
+        if NOT_SOURCE.iter().any(|skipped| name == *skipped) {
+            continue;
+        }
+        if path.is_dir() {
+            source_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_source_hash() {
+        let root = env::temp_dir().join("aoc-source-hash-test");
+        let _ = fs::remove_dir_all(&root);
+        let day = root.join("2024").join("day3");
+        let common = root.join("common");
+        for dir in [&day, &common] {
+            fs::create_dir_all(dir.join("src")).unwrap();
+            fs::write(dir.join("Cargo.toml"), "[package]").unwrap();
+        }
+        fs::write(root.join("Cargo.toml"), "[workspace]").unwrap();
+        fs::write(root.join("Cargo.lock"), "version = 3").unwrap();
+        fs::write(day.join("src").join("lib.rs"), "fn a() {}").unwrap();
+        fs::write(common.join("src").join("lib.rs"), "fn c() {}").unwrap();
+        let crates = [day.clone(), common.clone()];
+        let hash = || {
+            let files = files(&root, &crates).unwrap();
+            super::hash(&root, &files).unwrap()
+        };
+        let before = hash();
+
+        // Inputs, answers, examples and benches are not sources
+        fs::write(day.join("src").join("input.txt"), "1 2").unwrap();
+        fs::write(day.join("answers.toml"), "part1 = \"3\"").unwrap();
+        fs::create_dir_all(day.join("examples")).unwrap();
+        fs::write(day.join("examples").join("a.txt"), "1 2").unwrap();
+        fs::create_dir_all(day.join("benches")).unwrap();
+        fs::write(day.join("benches").join("bench.rs"), "fn b() {}").unwrap();
+        assert_eq!(hash(), before);
+
+        fs::write(day.join("src").join("lib.rs"), "fn b() {}").unwrap();
+        let after_day = hash();
+        assert_ne!(after_day, before);
+        fs::write(common.join("src").join("lib.rs"), "fn d() {}").unwrap();
+        let after_common = hash();
+        assert_ne!(after_common, after_day);
+        fs::write(day.join("Cargo.toml"), "[package] # edited").unwrap();
+        let after_manifest = hash();
+        assert_ne!(after_manifest, after_common);
+        fs::write(root.join("Cargo.lock"), "version = 4").unwrap();
+        assert_ne!(hash(), after_manifest);
+    }
+}
@@ -0,0 +1,335 @@
+use crate::client::Client;
+use crate::runner::Part;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the previous answer, it was not checked.
+    TooRecent,
+    /// The part is already solved or not unlocked yet.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    fn is_wrong(&self) -> bool {
+        matches!(self, Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong)
+    }
+}
+
+/// One submitted answer as kept in the history file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Guess {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the submission.
+    pub at: u64,
+    /// Seconds the server asked to wait before the next answer.
+    #[serde(default)]
+    pub wait: u64,
+}
+
+/// Every answer submitted for a year, one JSON object per line.
+pub struct History {
+    path: PathBuf,
+    pub guesses: Vec<Guess>,
+}
+
+impl History {
+    pub fn load(client: &Client) -> Result<Self, String> {
+        let path = client
+            .config
+            .cache_dir
+            .join(client.config.year.to_string())
+            .join("guesses.jsonl");
+        let guesses = match fs::read_to_string(&path) {
+            Ok(content) => content
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(serde_json::from_str)
+                .collect::<Result<_, _>>()
+                .map_err(|err| format!("{}: {err}", path.display()))?,
+            Err(_) => vec![],
+        };
+        Ok(History { path, guesses })
+    }
+
+    fn append(&mut self, guess: Guess) -> Result<(), String> {
+        let line = serde_json::to_string(&guess).expect("a guess serializes");
+        fs::create_dir_all(self.path.parent().expect("history has a parent"))
+            .and_then(|_| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(&self.path)
+            })
+            .and_then(|mut file| writeln!(file, "{line}"))
+            .map_err(|err| format!("{}: {err}", self.path.display()))?;
+        self.guesses.push(guess);
+        Ok(())
+    }
+
+    /// Refuses answers the history already rules out, and answers sent
+    /// before the wait the server asked for is over.
+    pub fn check(
+        &self,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        if let Some(last) = self.guesses.last() {
+            let ready = last.at + last.wait;
+            if now < ready {
+                return Err(format!("wait {}s more", ready - now));
+            }
+        }
+
+        let guesses = self
+            .guesses
+            .iter()
+            .filter(|guess| guess.day == day && guess.part == part);
+        let value: Option<i128> = answer.parse().ok();
+        for guess in guesses {
+            let known = guess.answer.parse::<i128>().ok();
+            match (&guess.outcome, value, known) {
+                (Outcome::Correct, _, _) => {
+                    return Err(format!(
+                        "already solved with {}",
+                        guess.answer
+                    ))
+                }
+                (outcome, _, _)
+                    if outcome.is_wrong() && guess.answer == answer =>
+                {
+                    return Err(format!("{answer} was already wrong"))
+                }
+                (Outcome::TooHigh, Some(value), Some(known))
+                    if value >= known =>
+                {
+                    return Err(format!("{known} was already too high"))
+                }
+                (Outcome::TooLow, Some(value), Some(known))
+                    if value <= known =>
+                {
+                    return Err(format!("{known} was already too low"))
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Checks an answer against the history, submits it and records the reply.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &str,
+) -> Result<(Outcome, String), String> {
+    let level = part.to_string();
+    let part = part.number();
+    let now = now();
+    history.check(day, part, answer, now)?;
+
+    let reply = client.post(
+        &format!("day/{day}/answer"),
+        &[("level", &level), ("answer", answer)],
+    )?;
+    if reply.status != 200 {
+        return Err(format!("server answered {}", reply.status));
+    }
+    let message = message(&reply.body);
+    let (outcome, wait) = parse_reply(&message);
+    history.append(Guess {
+        day,
+        part,
+        answer: answer.to_string(),
+        outcome: outcome.clone(),
+        at: now,
+        wait,
+    })?;
+    Ok((outcome, message))
+}
+
+/// The text of the `<article>` the server puts its reply in.
+fn message(body: &str) -> String {
+    let article = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+fn parse_reply(message: &str) -> (Outcome, u64) {
+    let outcome = if message.contains("That's the right answer") {
+        Outcome::Correct
+    } else if message.contains("You gave an answer too recently") {
+        Outcome::TooRecent
+    } else if message.contains("solving the right level") {
+        Outcome::WrongLevel
+    } else if message.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if message.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if message.contains("That's not the right answer") {
+        Outcome::Wrong
+    } else {
+        Outcome::Unknown
+    };
+    (outcome, parse_wait(message))
+}
+
+/// Seconds to wait from "You have 1m 5s left to wait" or "please wait
+/// 5 minutes before trying again".
+fn parse_wait(message: &str) -> u64 {
+    if let Some((before, _)) = message.split_once(" left to wait") {
+        let amounts = before.rsplit_once("You have ").map_or("", |(_, a)| a);
+        return amounts
+            .split_whitespace()
+            .filter_map(|amount| {
+                let unit = amount.chars().last()?;
+                let n: u64 =
+                    amount[..amount.len() - unit.len_utf8()].parse().ok()?;
+                match unit {
+                    'h' => Some(n * 3600),
+                    'm' => Some(n * 60),
+                    's' => Some(n),
+                    _ => None,
+                }
+            })
+            .sum();
+    }
+    let Some((_, after)) = message.split_once("wait ") else {
+        return 0;
+    };
+    let mut words = after.split_whitespace();
+    let count = match words.next() {
+        Some("one") | Some("a") => 1,
+        Some("two") => 2,
+        Some("three") => 3,
+        Some("four") => 4,
+        Some("five") => 5,
+        Some("ten") => 10,
+        Some(n) => n.parse().unwrap_or(0),
+        None => 0,
+    };
+    match words.next() {
+        Some(unit) if unit.starts_with("minute") => count * 60,
+        Some(unit) if unit.starts_with("second") => count,
+        _ => 0,
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("the clock is after 1970")
+        .as_secs()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock;
+
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; \
+        your answer is too high.  If you're stuck, make sure you're using \
+        the full input data.  Please wait one minute before trying again. \
+        [<a href=\"/2024/day/1\">Return to Day 1</a>]</p></article></main>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; \
+        you have to wait after submitting an answer before trying again. \
+        You have 1m 5s left to wait.</p></article>";
+    const CORRECT: &str = "<article><p>That's the right answer!  You are \
+        <span class=\"day-success\">one gold star</span> closer.</p>\
+        </article>";
+
+    fn guess(answer: &str, outcome: Outcome) -> Guess {
+        Guess {
+            day: 1,
+            part: 1,
+            answer: answer.to_string(),
+            outcome,
+            at: 0,
+            wait: 60,
+        }
+    }
+
+    #[test]
+    fn test_parse_reply() {
+        assert_eq!(parse_reply(&message(TOO_HIGH)), (Outcome::TooHigh, 60));
+        assert_eq!(
+            parse_reply(&message(TOO_RECENT)),
+            (Outcome::TooRecent, 65)
+        );
+        assert_eq!(parse_reply(&message(CORRECT)), (Outcome::Correct, 0));
+        assert!(message(CORRECT).starts_with("That's the right answer!"));
+    }
+
+    #[test]
+    fn test_check_uses_bounds() {
+        let history = History {
+            path: PathBuf::new(),
+            guesses: vec![
+                guess("100", Outcome::TooHigh),
+                guess("10", Outcome::TooLow),
+                guess("50", Outcome::Wrong),
+            ],
+        };
+        assert!(history.check(1, 1, "42", 30).is_err());
+        assert!(history.check(1, 1, "42", 60).is_ok());
+        assert!(history.check(1, 1, "100", 60).is_err());
+        assert!(history.check(1, 1, "120", 60).is_err());
+        assert!(history.check(1, 1, "10", 60).is_err());
+        assert!(history.check(1, 1, "50", 60).is_err());
+        assert!(history.check(1, 2, "120", 60).is_ok());
+    }
+
+    #[test]
+    fn test_submit_records_guesses() {
+        let server = mock::serve(vec![(200, TOO_HIGH), (200, CORRECT)]);
+        let client = Client::new(mock::config(&server, "aoc-submit"));
+        let mut history = History::load(&client).unwrap();
+
+        let (outcome, _) =
+            submit(&client, &mut history, 1, Part::One, "900").unwrap();
+        assert_eq!(outcome, Outcome::TooHigh);
+        history.guesses[0].wait = 0;
+        assert!(submit(&client, &mut history, 1, Part::One, "901").is_err());
+        let (outcome, _) =
+            submit(&client, &mut history, 1, Part::One, "42").unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+
+        let requests = server.requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].starts_with("POST /2024/day/1/answer "));
+        assert!(requests[0].ends_with("level=1&answer=900"));
+        let saved = History::load(&client).unwrap();
+        assert_eq!(saved.guesses.len(), 2);
+        assert_eq!(saved.guesses[1].outcome, Outcome::Correct);
+    }
+}
@@ -0,0 +1,129 @@
+use common::crypt::Key;
+use common::input::encrypted_path;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What `encrypt` did with the input of one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Written(PathBuf),
+    /// Already encrypted with the key.
+    Unchanged(PathBuf),
+}
+
+/// Encrypts the input of every day in `day_dirs` with `to` into
+/// `src/input.txt.enc`. The input is `src/input.txt`, or else the encrypted
+/// one decrypted with `from`, so passing another `to` rotates the key. Days
+/// without an input are left out. Every input is read before the first is
+/// written, so a wrong `from` changes nothing, and one already encrypted
+/// with `to` is taken as is, so a rotation cut short can be run again.
+pub fn encrypt(
+    day_dirs: &[PathBuf],
+    from: &Key,
+    to: &Key,
+) -> Result<Vec<Outcome>, String> {
+    let mut inputs = vec![];
+    for dir in day_dirs {
+        let encrypted = encrypted_path(dir);
+        let plaintext = dir.join("src").join("input.txt");
+        let input = match fs::read_to_string(&plaintext) {
+            Ok(input) => input,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                match read(&encrypted)? {
+                    Some(data) => from
+                        .decrypt(&data)
+                        .or_else(|err| to.decrypt(&data).map_err(|_| err))
+                        .map_err(|err| {
+                            format!("{}: {err}", encrypted.display())
+                        })?,
+                    None => continue,
+                }
+            }
+            Err(err) => return Err(format!("{}: {err}", plaintext.display())),
+        };
+        let current = read(&encrypted)?
+            .and_then(|data| to.decrypt(&data).ok())
+            .is_some_and(|existing| existing == input);
+        inputs.push((encrypted, input, current));
+    }
+
+    inputs
+        .into_iter()
+        .map(|(path, input, current)| {
+            if current {
+                return Ok(Outcome::Unchanged(path));
+            }
+            // Written aside and moved in place, so a failed write leaves
+            // the old file whole
+            let error = |err: io::Error| format!("{}: {err}", path.display());
+            let partial = path.with_extension("enc.partial");
+            fs::write(&partial, to.encrypt(&input)).map_err(error)?;
+            fs::rename(&partial, &path).map_err(error)?;
+            Ok(Outcome::Written(path))
+        })
+        .collect()
+}
+
+fn read(path: &Path) -> Result<Option<Vec<u8>>, String> {
+    match fs::read(path) {
+        Ok(data) => Ok(Some(data)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(format!("{}: {err}", path.display())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_encrypt() {
+        let root = env::temp_dir().join("aoc-vault-test");
+        let _ = fs::remove_dir_all(&root);
+        let dirs: Vec<PathBuf> =
+            (1..=3).map(|day| root.join(format!("day{day}"))).collect();
+        for dir in &dirs {
+            fs::create_dir_all(dir.join("src")).unwrap();
+        }
+        fs::write(dirs[0].join("src").join("input.txt"), "1 2").unwrap();
+        fs::write(dirs[1].join("src").join("input.txt"), "3 4").unwrap();
+
+        let key = Key::generate();
+        let written = encrypt(&dirs, &key, &key).unwrap();
+        assert_eq!(
+            written,
+            [
+                Outcome::Written(encrypted_path(&dirs[0])),
+                Outcome::Written(encrypted_path(&dirs[1]))
+            ]
+        );
+        let again = encrypt(&dirs, &key, &key).unwrap();
+        assert!(matches!(again[0], Outcome::Unchanged(_)));
+
+        // Rotating works from the encrypted copy alone
+        fs::remove_file(dirs[1].join("src").join("input.txt")).unwrap();
+        let rotated = Key::generate();
+        assert!(encrypt(&dirs, &rotated, &rotated).is_err());
+        assert_eq!(encrypt(&dirs, &key, &rotated).unwrap().len(), 2);
+        let data = fs::read(encrypted_path(&dirs[1])).unwrap();
+        assert_eq!(rotated.decrypt(&data).unwrap(), "3 4");
+        assert!(!encrypted_path(&dirs[1])
+            .with_extension("enc.partial")
+            .exists());
+
+        // A rotation cut short after the first day goes on from there
+        fs::remove_file(dirs[0].join("src").join("input.txt")).unwrap();
+        let data = key.encrypt("1 2");
+        fs::write(encrypted_path(&dirs[0]), data).unwrap();
+        let again = encrypt(&dirs, &key, &rotated).unwrap();
+        assert_eq!(
+            again,
+            [
+                Outcome::Written(encrypted_path(&dirs[0])),
+                Outcome::Unchanged(encrypted_path(&dirs[1]))
+            ]
+        );
+    }
+}
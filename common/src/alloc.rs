@@ -0,0 +1,155 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// A global allocator that counts what each thread allocates, on top of
+/// the system one. A binary installs it with
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: common::alloc::Counting = common::alloc::Counting;
+/// ```
+///
+/// after which [`measure`] reports the allocations of a closure. Counts are
+/// per thread, so parts running side by side on a thread pool do not mix.
+pub struct Counting;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct Counts {
+    allocations: u64,
+    bytes: u64,
+    /// Bytes allocated and not freed yet. Goes below zero when the thread
+    /// frees memory another thread allocated.
+    live: i64,
+    peak: i64,
+}
+
+thread_local! {
+    static COUNTS: Cell<Counts> = const {
+        Cell::new(Counts { allocations: 0, bytes: 0, live: 0, peak: 0 })
+    };
+}
+
+fn count(update: impl FnOnce(&mut Counts)) {
+    // Fails only while the thread is being torn down
+    let _ = COUNTS.try_with(|counts| {
+        let mut current = counts.get();
+        update(&mut current);
+        counts.set(current);
+    });
+}
+
+fn allocated(size: usize) {
+    count(|counts| {
+        counts.allocations += 1;
+        counts.bytes += size as u64;
+        counts.live += size as i64;
+        counts.peak = counts.peak.max(counts.live);
+    });
+}
+
+fn freed(size: usize) {
+    count(|counts| counts.live -= size as i64);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            allocated(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        freed(layout.size());
+    }
+
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        layout: Layout,
+        new_size: usize,
+    ) -> *mut u8 {
+        let new = System.realloc(ptr, layout, new_size);
+        if !new.is_null() {
+            // A move to a bigger block, counted as one more allocation
+            freed(layout.size());
+            allocated(new_size);
+        }
+        new
+    }
+}
+
+/// Bytes this thread holds, counting only what it allocated and freed.
+pub(crate) fn live() -> i64 {
+    COUNTS.with(Cell::get).live
+}
+
+/// What a closure allocated on its thread.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Calls to allocate or grow a block.
+    pub allocations: u64,
+    /// Bytes asked for over all those calls.
+    pub bytes: u64,
+    /// Most bytes held at once, above what was held before the closure.
+    pub peak: u64,
+}
+
+/// Runs `f` and reports what it allocated. Always zero unless [`Counting`]
+/// is the global allocator.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Usage) {
+    let before = COUNTS.with(Cell::get);
+    COUNTS.with(|counts| {
+        counts.set(Counts {
+            peak: before.live,
+            ..before
+        })
+    });
+    let result = f();
+    let after = COUNTS.with(Cell::get);
+    // Restore the outer peak, so that nested measures do not lower it
+    COUNTS.with(|counts| {
+        counts.set(Counts {
+            peak: before.peak.max(after.peak),
+            ..after
+        })
+    });
+    let usage = Usage {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+        peak: (after.peak - before.live).max(0) as u64,
+    };
+    (result, usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: Counting = Counting;
+
+    #[test]
+    fn test_measure() {
+        let (_, usage) = measure(|| {
+            let kept = vec![0u8; 1000];
+            drop(vec![0u8; 500]);
+            let (_, inner) = measure(|| drop(vec![0u8; 2000]));
+            assert_eq!(inner.peak, 2000);
+            kept
+        });
+        assert_eq!(usage.allocations, 3);
+        assert_eq!(usage.bytes, 3500);
+        assert_eq!(usage.peak, 3000);
+    }
+}
@@ -0,0 +1,59 @@
+/// Defines divan benchmarks for the parse step and both parts of a day,
+/// run on the day's default input. Used from `benches/solution.rs`:
+///
+/// ```ignore
+/// common::solution_benches!(day1::Day1);
+///
+/// fn main() {
+///     divan::main();
+/// }
+/// ```
+#[macro_export]
+macro_rules! solution_benches {
+    ($krate:ident :: $solution:ident) => {
+        fn input() -> String {
+            $crate::input::Source::Default
+                .load(
+                    ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")),
+                    $krate::INPUT,
+                )
+                .expect("benchmarks need src/input.txt or a key for the .enc")
+        }
+
+        #[divan::bench]
+        fn parse(bencher: divan::Bencher) {
+            let input = input();
+            bencher.bench(|| {
+                <$krate::$solution as $crate::Solution>::parse(
+                    divan::black_box(&input),
+                )
+            });
+        }
+
+        #[divan::bench(sample_count = 10)]
+        fn part1(bencher: divan::Bencher) {
+            let input = input();
+            let parsed =
+                <$krate::$solution as $crate::Solution>::parse(&input)
+                    .expect("the input parses");
+            bencher.bench(|| {
+                <$krate::$solution as $crate::Solution>::part1(
+                    divan::black_box(&parsed),
+                )
+            });
+        }
+
+        #[divan::bench(sample_count = 10)]
+        fn part2(bencher: divan::Bencher) {
+            let input = input();
+            let parsed =
+                <$krate::$solution as $crate::Solution>::parse(&input)
+                    .expect("the input parses");
+            bencher.bench(|| {
+                <$krate::$solution as $crate::Solution>::part2(
+                    divan::black_box(&parsed),
+                )
+            });
+        }
+    };
+}
@@ -0,0 +1,116 @@
+use crate::{alloc, AocError};
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Limits on the time and heap of one part. The runner sets them around a
+/// part with [`within`]; long loops call [`check`] to stop once over.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    pub time: Option<Duration>,
+    /// Bytes of heap the part may hold at once. Only enforced with
+    /// [`alloc::Counting`] as the global allocator.
+    pub memory: Option<u64>,
+}
+
+impl Budget {
+    pub fn timed_out(time: Duration) -> String {
+        format!("timed out after {time:?}")
+    }
+
+    pub fn over_memory(memory: u64) -> String {
+        format!(
+            "over the memory budget of {:.1}MiB",
+            memory as f64 / 1_048_576.0
+        )
+    }
+}
+
+struct Active {
+    budget: Budget,
+    start: Instant,
+    /// Heap held when the part started.
+    live: i64,
+    cancelled: Arc<AtomicBool>,
+}
+
+thread_local! {
+    static ACTIVE: RefCell<Option<Active>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` under `budget`. Setting `cancelled` makes every later [`check`]
+/// in `f` fail, for a runner that gave up waiting.
+pub fn within<R>(
+    budget: Budget,
+    cancelled: Arc<AtomicBool>,
+    f: impl FnOnce() -> R,
+) -> R {
+    let active = Active {
+        budget,
+        start: Instant::now(),
+        live: alloc::live(),
+        cancelled,
+    };
+    let outer = ACTIVE.with(|cell| cell.replace(Some(active)));
+    // Restored even if `f` panics, for callers that catch it
+    let _restore = Restore(outer);
+    f()
+}
+
+struct Restore(Option<Active>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        ACTIVE.with(|cell| cell.replace(self.0.take()));
+    }
+}
+
+/// Fails once the part running on this thread is over its budget or was
+/// cancelled. Cheap enough to call once per step of a long loop; outside
+/// of [`within`] it always passes.
+pub fn check() -> Result<(), AocError> {
+    ACTIVE.with(|cell| {
+        let Some(active) = &*cell.borrow() else {
+            return Ok(());
+        };
+        if let Some(time) = active.budget.time {
+            if active.cancelled.load(Ordering::Relaxed)
+                || active.start.elapsed() > time
+            {
+                return Err(AocError::new(Budget::timed_out(time)));
+            }
+        }
+        if let Some(memory) = active.budget.memory {
+            let held = alloc::live() - active.live;
+            if held > memory as i64 {
+                return Err(AocError::new(Budget::over_memory(memory)));
+            }
+        }
+        Ok(())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert_eq!(check(), Ok(()));
+        let budget = Budget {
+            time: Some(Duration::from_millis(10)),
+            memory: None,
+        };
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let steps = within(budget, cancelled.clone(), || {
+            (0..).take_while(|_| check().is_ok()).count()
+        });
+        assert!(steps > 0);
+
+        cancelled.store(true, Ordering::Relaxed);
+        let err = within(budget, cancelled, check).unwrap_err();
+        assert_eq!(err.message(), "timed out after 10ms");
+        assert_eq!(check(), Ok(()));
+    }
+}
@@ -0,0 +1,134 @@
+use chacha20poly1305::aead::{Aead, AeadCore, KeyInit, OsRng};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use std::env;
+use std::fmt::{Debug, Formatter};
+use std::path::Path;
+
+/// Variable holding the key, in the environment or in a `.env` file.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+/// What an encrypted input starts with, followed by the nonce and the
+/// ciphertext.
+const MAGIC: &[u8] = b"aoc-input-v1\n";
+const NONCE_LEN: usize = 24;
+
+/// A key for encrypting puzzle inputs, written as 64 hex digits.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Key([u8; 32]);
+
+impl Debug for Key {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Keeps the key out of logs and panic messages
+        f.write_str("Key(..)")
+    }
+}
+
+impl Key {
+    pub fn generate() -> Self {
+        Key(XChaCha20Poly1305::generate_key(&mut OsRng).into())
+    }
+
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        let hex = hex.trim();
+        let invalid = || format!("{KEY_VAR} must be 64 hex digits");
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (byte, pair) in key.iter_mut().zip(hex.as_bytes().chunks(2)) {
+            let pair = std::str::from_utf8(pair).map_err(|_| invalid())?;
+            *byte = u8::from_str_radix(pair, 16).map_err(|_| invalid())?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn to_hex(&self) -> String {
+        self.0.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// The key from [`KEY_VAR`] in the environment, or else in the nearest
+    /// `.env` at or above `dir`. `None` if neither sets it.
+    pub fn find(dir: &Path) -> Result<Option<Self>, String> {
+        if let Ok(hex) = env::var(KEY_VAR) {
+            return non_empty(&hex).map(Key::from_hex).transpose();
+        }
+        let Some(dotenv) = dir
+            .ancestors()
+            .map(|dir| dir.join(".env"))
+            .find(|path| path.is_file())
+        else {
+            return Ok(None);
+        };
+        let vars = dotenvy::from_path_iter(&dotenv)
+            .map_err(|err| format!("{}: {err}", dotenv.display()))?;
+        for var in vars {
+            let (name, value) =
+                var.map_err(|err| format!("{}: {err}", dotenv.display()))?;
+            if name == KEY_VAR {
+                return non_empty(&value).map(Key::from_hex).transpose();
+            }
+        }
+        Ok(None)
+    }
+
+    pub fn encrypt(&self, input: &str) -> Vec<u8> {
+        let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+        let ciphertext = self
+            .cipher()
+            .encrypt(&nonce, input.as_bytes())
+            .expect("inputs are far below the size limit of the cipher");
+        [MAGIC, nonce.as_slice(), &ciphertext].concat()
+    }
+
+    pub fn decrypt(&self, data: &[u8]) -> Result<String, String> {
+        let data = data
+            .strip_prefix(MAGIC)
+            .filter(|data| data.len() >= NONCE_LEN)
+            .ok_or("not an encrypted input")?;
+        let (nonce, ciphertext) = data.split_at(NONCE_LEN);
+        let input = self
+            .cipher()
+            .decrypt(XNonce::from_slice(nonce), ciphertext)
+            .map_err(|_| {
+                format!("cannot decrypt, {KEY_VAR} is a different key")
+            })?;
+        String::from_utf8(input)
+            .map_err(|_| "the decrypted input is not UTF-8".to_string())
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+fn non_empty(value: &str) -> Option<&str> {
+    Some(value).filter(|value| !value.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::generate();
+        let encrypted = key.encrypt("3   4\n4   3\n");
+        assert!(encrypted.starts_with(MAGIC));
+        assert_eq!(key.decrypt(&encrypted).unwrap(), "3   4\n4   3\n");
+        // A fresh nonce every time
+        assert_ne!(encrypted, key.encrypt("3   4\n4   3\n"));
+
+        assert!(Key::generate().decrypt(&encrypted).is_err());
+        assert!(key.decrypt(b"3   4\n").is_err());
+        assert!(key.decrypt(&encrypted[..MAGIC.len() + 4]).is_err());
+    }
+
+    #[test]
+    fn test_hex() {
+        let key = Key::generate();
+        assert_eq!(Key::from_hex(&key.to_hex()), Ok(key));
+        assert!(Key::from_hex("abc").is_err());
+        assert!(Key::from_hex(&"g".repeat(64)).is_err());
+        assert!(Key::from_hex(&"é".repeat(32)).is_err());
+    }
+}
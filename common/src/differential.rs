@@ -0,0 +1,130 @@
+use crate::Solution;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::env;
+
+/// Seeds a differential test runs unless `AOC_SEEDS` asks for more or fewer.
+pub const DEFAULT_SEEDS: u64 = 1000;
+
+/// The answers a brute-force reference gives for a generated input. A part
+/// the reference cannot solve, or that is too slow to brute-force, is left
+/// as `None` and not compared.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expected {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Expected {
+    pub fn both(part1: impl ToString, part2: impl ToString) -> Self {
+        Expected {
+            part1: Some(part1.to_string()),
+            part2: Some(part2.to_string()),
+        }
+    }
+
+    pub fn part1(part1: impl ToString) -> Self {
+        Expected {
+            part1: Some(part1.to_string()),
+            part2: None,
+        }
+    }
+}
+
+/// The number of seeds to run: `AOC_SEEDS` if set, else `seeds`.
+pub fn seeds(seeds: u64) -> u64 {
+    env::var("AOC_SEEDS")
+        .ok()
+        .and_then(|seeds| seeds.parse().ok())
+        .unwrap_or(seeds)
+}
+
+/// Compares `S` against `reference` on inputs from `generate`, one for each
+/// seed, with sizes cycling from 1 up to `max_size`. Panics with the seed
+/// and the input at the first difference, so a failure can be replayed.
+pub fn check<S: Solution>(
+    seeds: u64,
+    max_size: usize,
+    generate: impl Fn(&mut StdRng, usize) -> String,
+    reference: impl Fn(&str) -> Expected,
+) {
+    for seed in 0..self::seeds(seeds) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let size = (seed as usize) % max_size.max(1) + 1;
+        let input = generate(&mut rng, size);
+        let expected = reference(&input);
+        let fail = |what: String| -> ! {
+            panic!("seed {seed}, size {size}: {what}\ninput:\n{input}")
+        };
+        let parsed =
+            S::parse(&input).unwrap_or_else(|err| fail(err.render(&input)));
+        if let Some(expected) = expected.part1 {
+            let answer = S::part1(&parsed).map(|answer| answer.to_string());
+            match answer {
+                Ok(answer) if answer == expected => {}
+                answer => fail(format!(
+                    "part1 gave {answer:?}, the reference {expected:?}"
+                )),
+            }
+        }
+        if let Some(expected) = expected.part2 {
+            let answer = S::part2(&parsed).map(|answer| answer.to_string());
+            match answer {
+                Ok(answer) if answer == expected => {}
+                answer => fail(format!(
+                    "part2 gave {answer:?}, the reference {expected:?}"
+                )),
+            }
+        }
+    }
+}
+
+/// Generates the `test_differential` and `test_fuzz` tests of a day, which
+/// check `generate::input` against `reference::expected` with [`check`]
+/// and feed it to the parts with [`crate::fuzz::check`]. Used from the
+/// day's test module, with `seeds` and `fuzz_input` optional:
+///
+/// ```ignore
+/// common::generator_tests! {
+///     Day19;
+///     seeds: 200;
+///     max_size: 8;
+///     fuzz_input: generate::fuzz_input;
+/// }
+/// ```
+#[macro_export]
+macro_rules! generator_tests {
+    (@or $value:expr; $default:expr) => {
+        $value
+    };
+    (@or ; $default:expr) => {
+        $default
+    };
+    (
+        $solution:ty;
+        $(seeds: $seeds:expr;)?
+        max_size: $max_size:expr;
+        $(fuzz_input: $fuzz_input:path;)?
+    ) => {
+        #[test]
+        fn test_differential() {
+            $crate::differential::check::<$solution>(
+                $crate::generator_tests!(
+                    @or $($seeds)?; $crate::differential::DEFAULT_SEEDS
+                ),
+                $max_size,
+                generate::input,
+                reference::expected,
+            );
+        }
+
+        #[test]
+        fn test_fuzz() {
+            $crate::fuzz::check::<$solution>(
+                env!("CARGO_MANIFEST_DIR"),
+                $crate::fuzz::DEFAULT_CASES,
+                $crate::generator_tests!(@or $($fuzz_input)?; generate::input),
+            );
+        }
+    };
+}
@@ -0,0 +1,152 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// An error in a puzzle input, or a puzzle that has no answer.
+///
+/// Parse errors point at the offending slice of the input. The slice is kept
+/// as an address so that the error does not borrow the input;
+/// [`AocError::location`] turns it into a line and column once the input is
+/// at hand again.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AocError {
+    message: String,
+    position: Position,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Unknown,
+    /// Address of the offending slice of the input.
+    Span(usize),
+    /// 1-based line and column.
+    At(usize, usize),
+}
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            message: message.into(),
+            position: Position::Unknown,
+        }
+    }
+
+    /// An error about `fragment`, which should be a slice of the input.
+    pub fn at(fragment: &str, message: impl Into<String>) -> Self {
+        AocError {
+            message: message.into(),
+            position: Position::Span(fragment.as_ptr() as usize),
+        }
+    }
+
+    /// An error at a 1-based line and column.
+    pub fn at_line(
+        line: usize,
+        column: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        AocError {
+            message: message.into(),
+            position: Position::At(line, column),
+        }
+    }
+
+    /// An unexpected character in a grid, at 0-based `row` and `col`.
+    pub fn unexpected(c: char, row: usize, col: usize) -> Self {
+        Self::at_line(row + 1, col + 1, format!("unexpected {c:?}"))
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    /// 1-based line and column of the error in `input`, when known.
+    pub fn location(&self, input: &str) -> Option<(usize, usize)> {
+        match self.position {
+            Position::Unknown => None,
+            Position::At(line, column) => Some((line, column)),
+            Position::Span(address) => {
+                let offset = address.checked_sub(input.as_ptr() as usize)?;
+                if offset > input.len() || !input.is_char_boundary(offset) {
+                    return None;
+                }
+                let before = &input[..offset];
+                let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+                Some((
+                    before.matches('\n').count() + 1,
+                    before[line_start..].chars().count() + 1,
+                ))
+            }
+        }
+    }
+
+    /// A diagnostic for `input`: the message, then the offending line with
+    /// a caret under the column.
+    pub fn render(&self, input: &str) -> String {
+        let Some((line, column)) = self.location(input) else {
+            return self.message.clone();
+        };
+        let text = input.lines().nth(line - 1).unwrap_or("");
+        let gutter = " ".repeat(line.to_string().len());
+        format!(
+            "line {line}, column {column}: {}\n{gutter} |\n{line} | {text}\n\
+             {gutter} | {}^",
+            self.message,
+            " ".repeat(column - 1)
+        )
+    }
+}
+
+impl Display for AocError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.position {
+            Position::At(line, column) => {
+                write!(f, "line {line}, column {column}: {}", self.message)
+            }
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for AocError {}
+
+impl From<nom::Err<nom::error::Error<&str>>> for AocError {
+    fn from(err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Incomplete(_) => AocError::new("incomplete input"),
+            nom::Err::Error(err) | nom::Err::Failure(err) => AocError::at(
+                err.input,
+                format!("cannot parse this ({})", err.code.description()),
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_location() {
+        let input = indoc! {"
+            3   4
+            4   x
+        "};
+        let token = input.lines().nth(1).unwrap().split_whitespace().nth(1);
+        let err =
+            AocError::at(token.unwrap(), "expected a number, found \"x\"");
+        assert_eq!(err.location(input), Some((2, 5)));
+        assert_eq!(err.location("elsewhere"), None);
+        assert_eq!(
+            err.render(input),
+            indoc! {r#"
+                line 2, column 5: expected a number, found "x"
+                  |
+                2 | 4   x
+                  |     ^"#}
+        );
+
+        let err = AocError::unexpected('?', 0, 2);
+        assert_eq!(err.to_string(), "line 1, column 3: unexpected '?'");
+    }
+}
@@ -0,0 +1,171 @@
+use crate::Solution;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+const FENCE: &str = "---";
+
+/// An example from a day's `examples/NAME.txt`. The answers the puzzle text
+/// gives for it go in a front matter header above the input:
+///
+/// ```text
+/// ---
+/// part1 = 11
+/// part2 = "31"
+/// ---
+/// 3   4
+/// 4   3
+/// ```
+///
+/// A file without the header is all input and has no answers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Example {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn parse(content: &str) -> Result<Self, String> {
+        let Some(rest) = content
+            .strip_prefix(FENCE)
+            .and_then(|rest| rest.strip_prefix('\n'))
+        else {
+            return Ok(Example {
+                input: content.to_string(),
+                ..Example::default()
+            });
+        };
+        let (header, input) = rest
+            .split_once(&format!("\n{FENCE}\n"))
+            .ok_or("the front matter is not closed with ---")?;
+
+        let mut example = Example {
+            input: input.to_string(),
+            ..Example::default()
+        };
+        for line in header.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("expected `key = value`: {line:?}"))?;
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            match key.trim() {
+                "part1" => example.part1 = Some(value.to_string()),
+                "part2" => example.part2 = Some(value.to_string()),
+                key => {
+                    return Err(format!("unknown front matter key {key:?}"))
+                }
+            }
+        }
+        Ok(example)
+    }
+
+    pub fn load(day_dir: &Path, name: &str) -> io::Result<Self> {
+        let path = day_dir.join("examples").join(format!("{name}.txt"));
+        let invalid = |err| {
+            let message = format!("{}: {err}", path.display());
+            io::Error::new(io::ErrorKind::InvalidData, message)
+        };
+        let content = fs::read_to_string(&path).map_err(|err| {
+            io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+        })?;
+        Self::parse(&content).map_err(invalid)
+    }
+
+    /// The expected answer of `part`, `"part1"` or `"part2"`.
+    pub fn expected(&self, part: &str) -> Option<&str> {
+        match part {
+            "part1" => self.part1.as_deref(),
+            "part2" => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Runs one part of `S` on an example and checks it against the answer in
+/// the front matter. This is the body of the tests `example_tests!`
+/// generates.
+pub fn check<S: Solution>(day_dir: &str, name: &str, part: &str) {
+    let example = Example::load(Path::new(day_dir), name)
+        .unwrap_or_else(|err| panic!("{err}"));
+    let expected = example.expected(part).unwrap_or_else(|| {
+        panic!("examples/{name}.txt has no {part} answer in its front matter")
+    });
+    let input = &example.input;
+    let answer = S::parse(input).and_then(|parsed| match part {
+        "part1" => S::part1(&parsed).map(|answer| answer.to_string()),
+        _ => S::part2(&parsed).map(|answer| answer.to_string()),
+    });
+    let answer = answer.unwrap_or_else(|err| panic!("{}", err.render(input)));
+    assert_eq!(answer, expected, "examples/{name}.txt {part}");
+}
+
+/// Generates one test per example and part, named `NAME::part1` and
+/// `NAME::part2`, that checks the answer against the front matter of the
+/// day's `examples/NAME.txt` (see [`Example`]). Used from the day's test
+/// module:
+///
+/// ```ignore
+/// common::example_tests! {
+///     Day1;
+///     example: part1, part2;
+///     larger: part1;
+/// }
+/// ```
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty; $($name:ident: $($part:ident),+;)+) => {
+        $(
+            mod $name {
+                use super::*;
+
+                $(
+                    #[test]
+                    fn $part() {
+                        $crate::example::check::<$solution>(
+                            env!("CARGO_MANIFEST_DIR"),
+                            stringify!($name),
+                            stringify!($part),
+                        );
+                    }
+                )+
+            }
+        )+
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_parse() {
+        let example = Example::parse(indoc! {r#"
+            ---
+            part1 = 11
+            # not given for this example
+            part2 = "1,2"
+            ---
+            3   4
+        "#})
+        .unwrap();
+        assert_eq!(example.expected("part1"), Some("11"));
+        assert_eq!(example.expected("part2"), Some("1,2"));
+        assert_eq!(example.input, "3   4\n");
+
+        let example = Example::parse("3   4\n").unwrap();
+        assert_eq!(example.part1, None);
+        assert_eq!(example.input, "3   4\n");
+
+        assert!(Example::parse("---\npart1 = 11\n3   4\n").is_err());
+        assert!(Example::parse("---\npart3 = 11\n---\n").is_err());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::budget::{self, Budget};
+use crate::differential;
+use crate::Solution;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Cases a fuzz test runs unless `AOC_SEEDS` asks for more or fewer.
+pub const DEFAULT_CASES: u64 = 1000;
+
+/// Time each part gets on a case. Parts that check their budget stop
+/// there, so one slow case does not hold up the rest.
+const CASE_TIME: Duration = Duration::from_millis(20);
+
+/// Pieces spliced into inputs: separators the parsers split on, numbers
+/// that just fit `u64` or `i64` and overflow whatever adds them up, numbers
+/// too large or negative for their types, and characters no puzzle uses.
+const TOKENS: &[&str] = &[
+    "0",
+    "7",
+    "-",
+    "-1",
+    "+",
+    ",",
+    ":",
+    "|",
+    " ",
+    "\n",
+    "\n\n",
+    "#",
+    ".",
+    "A",
+    "X=",
+    "é",
+    "18446744073709551615",
+    "9223372036854775808",
+    "9223372036854775807",
+    "99999999999999999999",
+    "18446744073709551616",
+];
+
+/// Numbers at the top of `u64` and `i64`, also among [`TOKENS`].
+const LARGE_NUMBERS: &[&str] = &[
+    "18446744073709551615",
+    "9223372036854775808",
+    "9223372036854775807",
+];
+
+/// Splices, drops or repeats a few pieces of `input`, or swaps one of its
+/// numbers for one that just fits a 64-bit type, so that the input still
+/// parses but whatever adds it up overflows.
+fn mutate(rng: &mut impl Rng, input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    for _ in 0..rng.gen_range(1..=4) {
+        let at = rng.gen_range(0..=chars.len());
+        let len = rng.gen_range(0..=8).min(chars.len() - at);
+        match rng.gen_range(0..5) {
+            4 => {
+                let start = chars[at..]
+                    .iter()
+                    .position(char::is_ascii_digit)
+                    .map_or(chars.len(), |i| at + i);
+                let end = chars[start..]
+                    .iter()
+                    .position(|c| !c.is_ascii_digit())
+                    .map_or(chars.len(), |i| start + i);
+                let number = LARGE_NUMBERS.choose(rng).unwrap();
+                chars.splice(start..end, number.chars());
+            }
+            0 => {
+                chars.drain(at..at + len);
+            }
+            1 => {
+                let token = TOKENS.choose(rng).unwrap();
+                chars.splice(at..at, token.chars());
+            }
+            2 => {
+                let token = TOKENS.choose(rng).unwrap();
+                chars.splice(at..at + len, token.chars());
+            }
+            _ => {
+                let repeated: Vec<char> = chars[at..at + len].to_vec();
+                chars.splice(at..at, repeated);
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// Parses `input` and, if that works, solves both parts of it, each under
+/// a short time budget. Either may fail, but only with an error; a panic
+/// is a bug. This is what the `cargo fuzz` targets run.
+pub fn solve<S: Solution>(input: &str) {
+    let Ok(parsed) = S::parse(input) else {
+        return;
+    };
+    let budget = Budget {
+        time: Some(CASE_TIME),
+        memory: None,
+    };
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let _ =
+        budget::within(budget, Arc::clone(&cancelled), || S::part1(&parsed));
+    let _ = budget::within(budget, cancelled, || S::part2(&parsed));
+}
+
+/// Runs [`solve`] on every input saved in `fuzz/regressions/YEAR/dayN/` for
+/// the day crate in `day_dir`, then on `cases` inputs from `generate` with
+/// a few random edits, and every so often a string of tokens alone. A
+/// panic is reported with the file or the case and the input; a new one
+/// goes into the regressions once fixed.
+pub fn check<S: Solution>(
+    day_dir: &str,
+    cases: u64,
+    generate: impl Fn(&mut StdRng, usize) -> String,
+) {
+    for (path, input) in regressions(Path::new(day_dir)) {
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            solve::<S>(&input);
+        }));
+        if solved.is_err() {
+            panic!("{} panicked again", path.display());
+        }
+    }
+    for case in 0..differential::seeds(cases) {
+        let mut rng = StdRng::seed_from_u64(case);
+        let input = match case % 8 {
+            0 => (0..rng.gen_range(0..16))
+                .map(|_| *TOKENS.choose(&mut rng).unwrap())
+                .collect(),
+            _ => {
+                let valid = generate(&mut rng, (case as usize) % 4 + 1);
+                mutate(&mut rng, &valid)
+            }
+        };
+        let solved = panic::catch_unwind(AssertUnwindSafe(|| {
+            solve::<S>(&input);
+        }));
+        if solved.is_err() {
+            panic!("case {case}: panicked on {input:?}");
+        }
+    }
+}
+
+/// The inputs in `fuzz/regressions/YEAR/dayN/` for the day crate in
+/// `YEAR/dayN`, in name order.
+fn regressions(day_dir: &Path) -> Vec<(std::path::PathBuf, String)> {
+    let (Some(day), Some(year)) = (
+        day_dir.file_name(),
+        day_dir.parent().and_then(Path::file_name),
+    ) else {
+        return vec![];
+    };
+    let root = day_dir.ancestors().nth(2).expect("checked the year above");
+    let dir = root.join("fuzz").join("regressions").join(year).join(day);
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return vec![];
+    };
+    let mut inputs: Vec<_> = entries
+        .map(|entry| {
+            let path = entry.expect("the directory is readable").path();
+            let input = fs::read_to_string(&path)
+                .unwrap_or_else(|err| panic!("{}: {err}", path.display()));
+            (path, input)
+        })
+        .collect();
+    inputs.sort();
+    inputs
+}
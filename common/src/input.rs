@@ -0,0 +1,187 @@
+use crate::crypt::{self, Key};
+use crate::example::Example;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+/// Defines `pub const INPUT: Option<&str>` for a day crate: its
+/// `src/input.txt` when the crate's `embed-input` feature is enabled, `None`
+/// otherwise, so the crate still compiles without the file. Embedding needs
+/// the plaintext file, not just `src/input.txt.enc`.
+#[macro_export]
+macro_rules! embedded_input {
+    () => {
+        #[cfg(feature = "embed-input")]
+        pub const INPUT: Option<&str> = Some(include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/input.txt"
+        )));
+        #[cfg(not(feature = "embed-input"))]
+        pub const INPUT: Option<&str> = None;
+    };
+}
+
+/// Where a day reads its puzzle input from.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Source {
+    /// `src/input.txt` of the day crate, falling back to the embedded copy
+    /// and then to `src/input.txt.enc` decrypted with [`crypt::KEY_VAR`].
+    #[default]
+    Default,
+    File(PathBuf),
+    Stdin,
+    /// `examples/<name>.txt` of the day crate, without its front matter.
+    Example(String),
+}
+
+impl Source {
+    /// Reads the source from command line arguments: nothing for the default
+    /// input, a path (or `-` for stdin), or `--example NAME`.
+    pub fn from_args<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+        let source = match args.next().as_deref() {
+            None => Source::Default,
+            Some("--example") => match args.next() {
+                Some(name) => Source::Example(name),
+                None => return Err("--example needs a name".to_string()),
+            },
+            Some("-") => Source::Stdin,
+            Some(path) => Source::File(PathBuf::from(path)),
+        };
+        if let Some(extra) = args.next() {
+            return Err(format!("unexpected argument {extra:?}"));
+        }
+        Ok(source)
+    }
+
+    pub fn load(
+        &self,
+        day_dir: &Path,
+        embedded: Option<&str>,
+    ) -> io::Result<String> {
+        match self {
+            Source::Default => {
+                let path = day_dir.join("src").join("input.txt");
+                match (read(&path), embedded) {
+                    (Err(err), Some(input))
+                        if err.kind() == io::ErrorKind::NotFound =>
+                    {
+                        Ok(input.to_string())
+                    }
+                    (Err(err), None)
+                        if err.kind() == io::ErrorKind::NotFound
+                            && encrypted_path(day_dir).exists() =>
+                    {
+                        decrypt(&encrypted_path(day_dir), Key::find(day_dir))
+                    }
+                    (result, _) => result,
+                }
+            }
+            Source::File(path) => read(path),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Source::Example(name) => {
+                Example::load(day_dir, name).map(|example| example.input)
+            }
+        }
+    }
+}
+
+/// Where a day keeps its encrypted input, next to `src/input.txt`.
+pub fn encrypted_path(day_dir: &Path) -> PathBuf {
+    day_dir.join("src").join("input.txt.enc")
+}
+
+/// Decrypts the input at `path`. Without a key the input counts as not
+/// found, so that runs and tests skip it as they skip a missing one.
+fn decrypt(
+    path: &Path,
+    key: Result<Option<Key>, String>,
+) -> io::Result<String> {
+    let error = |kind, message: String| {
+        io::Error::new(kind, format!("{}: {message}", path.display()))
+    };
+    let key = key
+        .map_err(|err| error(io::ErrorKind::InvalidInput, err))?
+        .ok_or_else(|| {
+            error(
+                io::ErrorKind::NotFound,
+                format!("set {} to decrypt it", crypt::KEY_VAR),
+            )
+        })?;
+    key.decrypt(&fs::read(path)?)
+        .map_err(|err| error(io::ErrorKind::InvalidData, err))
+}
+
+fn read(path: &Path) -> io::Result<String> {
+    fs::read_to_string(path).map_err(|err| {
+        io::Error::new(err.kind(), format!("{}: {err}", path.display()))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(Source::from_args(args(&[])), Ok(Source::Default));
+        assert_eq!(Source::from_args(args(&["-"])), Ok(Source::Stdin));
+        assert_eq!(
+            Source::from_args(args(&["my.txt"])),
+            Ok(Source::File(PathBuf::from("my.txt")))
+        );
+        assert_eq!(
+            Source::from_args(args(&["--example", "larger"])),
+            Ok(Source::Example("larger".to_string()))
+        );
+        assert!(Source::from_args(args(&["--example"])).is_err());
+        assert!(Source::from_args(args(&["a.txt", "b.txt"])).is_err());
+    }
+
+    #[test]
+    fn test_load() {
+        let day_dir = env::temp_dir().join("common-input-test");
+        fs::create_dir_all(day_dir.join("examples")).unwrap();
+        fs::write(
+            day_dir.join("examples").join("small.txt"),
+            "---\npart1 = 3\n---\n1 2",
+        )
+        .unwrap();
+
+        let example = Source::Example("small".to_string());
+        assert_eq!(example.load(&day_dir, None).unwrap(), "1 2");
+        assert_eq!(
+            Source::Default.load(&day_dir, Some("embedded")).unwrap(),
+            "embedded"
+        );
+        let missing = Source::Default.load(&day_dir, None).unwrap_err();
+        assert_eq!(missing.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_decrypt() {
+        let day_dir = env::temp_dir().join("common-input-decrypt-test");
+        fs::create_dir_all(day_dir.join("src")).unwrap();
+        let key = Key::generate();
+        let path = encrypted_path(&day_dir);
+        fs::write(&path, key.encrypt("1 2")).unwrap();
+
+        assert_eq!(decrypt(&path, Ok(Some(key))).unwrap(), "1 2");
+        let locked = decrypt(&path, Ok(None)).unwrap_err();
+        assert_eq!(locked.kind(), io::ErrorKind::NotFound);
+        let wrong = decrypt(&path, Ok(Some(Key::generate()))).unwrap_err();
+        assert_eq!(wrong.kind(), io::ErrorKind::InvalidData);
+    }
+}
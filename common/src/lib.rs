@@ -1,12 +1,30 @@
 use std::fmt::{Display, Formatter};
 use std::ops::{Index, IndexMut};
 
+pub mod alloc;
+mod bench;
+pub mod budget;
+pub mod crypt;
+pub mod differential;
+mod error;
+pub mod example;
+pub mod fuzz;
+pub mod input;
+pub mod parse;
+pub mod progress;
+mod solution;
+pub mod stepper;
+pub mod viz;
+
+pub use error::AocError;
+pub use solution::{run, NoAnswer, Solution};
+
 pub type Coord = (usize, usize);
 pub type Direction = (isize, isize);
 
 pub const DIRECTIONS: [(isize, isize); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
 
-#[derive(Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Matrix<T> {
     pub matrix: Vec<Vec<T>>,
     pub height: usize,
@@ -75,23 +93,60 @@ impl<T: Clone + Default> Matrix<T> {
         None
     }
 
-    pub fn from<F>(input: &str, parser_func: F) -> Self
+    /// Parses a grid with one cell per character, rejecting empty input,
+    /// ragged lines and characters that `parser_func` has no cell for.
+    pub fn parse<F>(input: &str, parser_func: F) -> Result<Self, AocError>
     where
-        F: Fn(char) -> T + Copy,
+        F: Fn(char) -> Option<T>,
     {
-        let matrix: Vec<Vec<T>> = input
-            .lines()
-            .map(|line| line.chars().map(parser_func).collect())
-            .collect();
+        // Errors point into `input` rather than at a line number, so the
+        // grid may be a block cut out of a larger puzzle input.
+        let lines: Vec<&str> = input.lines().collect();
+        let matrix = lines
+            .iter()
+            .map(|line| {
+                line.char_indices()
+                    .map(|(i, c)| {
+                        parser_func(c).ok_or_else(|| {
+                            AocError::at(
+                                &line[i..],
+                                format!("unexpected {c:?}"),
+                            )
+                        })
+                    })
+                    .collect()
+            })
+            .collect::<Result<Vec<Vec<T>>, _>>()?;
 
         let height = matrix.len();
-        let width = matrix[0].len();
+        let width = matrix.first().map_or(0, Vec::len);
+        if width == 0 {
+            return Err(AocError::at(
+                input,
+                "expected a grid, found no cells",
+            ));
+        }
+        if let Some(row) = matrix.iter().position(|row| row.len() != width) {
+            return Err(AocError::at(
+                lines[row],
+                format!("expected {width} cells like the first line"),
+            ));
+        }
 
-        Self {
+        Ok(Self {
             matrix,
             height,
             width,
-        }
+        })
+    }
+
+    /// Parses a grid where every character has a cell, rejecting empty
+    /// input and ragged lines.
+    pub fn from<F>(input: &str, parser_func: F) -> Result<Self, AocError>
+    where
+        F: Fn(char) -> T + Copy,
+    {
+        Self::parse(input, |c| Some(parser_func(c)))
     }
 }
 
@@ -106,3 +161,101 @@ impl<T: Clone + Default + Display> Display for Matrix<T> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Grids of 1 to 8 rows and columns over a few cell characters.
+    fn grid() -> impl Strategy<Value = Vec<Vec<char>>> {
+        (1..8usize, 1..8usize).prop_flat_map(|(height, width)| {
+            let cell = prop::sample::select(vec!['#', '.', 'S', 'E', '0']);
+            prop::collection::vec(prop::collection::vec(cell, width), height)
+        })
+    }
+
+    fn matrix(rows: Vec<Vec<char>>) -> Matrix<char> {
+        let (height, width) = (rows.len(), rows[0].len());
+        Matrix {
+            matrix: rows,
+            height,
+            width,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_display_round_trip(rows in grid()) {
+            let text: String = rows
+                .iter()
+                .map(|row| row.iter().collect::<String>() + "\n")
+                .collect();
+            let parsed = Matrix::from(&text, |c| c).unwrap();
+            prop_assert_eq!(parsed.to_string(), text);
+            prop_assert_eq!(parsed, matrix(rows));
+        }
+
+        #[test]
+        fn test_in_bounds_isize(
+            rows in grid(),
+            row in -10..10isize,
+            col in -10..10isize,
+        ) {
+            let matrix = matrix(rows);
+            let in_bounds = row >= 0
+                && col >= 0
+                && matrix.in_bounds(row as usize, col as usize);
+            prop_assert_eq!(matrix.in_bounds_isize(row, col), in_bounds);
+        }
+
+        #[test]
+        fn test_neighbours(
+            rows in grid(),
+            row in 0..8usize,
+            col in 0..8usize,
+        ) {
+            let matrix = matrix(rows);
+            let pos = (row % matrix.height, col % matrix.width);
+            for ((i, j), (di, dj)) in matrix.get_coord_neighbours(pos) {
+                prop_assert!(matrix.in_bounds(i, j));
+                prop_assert_eq!(
+                    (i as isize - pos.0 as isize, j as isize - pos.1 as isize),
+                    (di, dj)
+                );
+                let back = matrix.get_coord_neighbours((i, j));
+                prop_assert!(back.contains(&(pos, (-di, -dj))));
+            }
+        }
+
+        #[test]
+        fn test_find_first_in_row_major_order(
+            rows in grid(),
+            value in prop::sample::select(vec!['#', '.', 'S', 'E', '0']),
+        ) {
+            let width = rows[0].len();
+            let first = rows
+                .iter()
+                .flatten()
+                .position(|&c| c == value)
+                .map(|i| (i / width, i % width));
+            prop_assert_eq!(matrix(rows).find(&value), first);
+        }
+
+        #[test]
+        fn test_ragged_input_is_an_error(
+            lengths in prop::collection::vec(1..8usize, 2..8),
+        ) {
+            prop_assume!(lengths.iter().any(|&len| len != lengths[0]));
+            let text: String =
+                lengths.iter().map(|&len| ".".repeat(len) + "\n").collect();
+            prop_assert!(Matrix::from(&text, |c| c).is_err());
+        }
+    }
+
+    #[test]
+    fn test_empty_input_is_an_error() {
+        assert!(Matrix::from("", |c| c).is_err());
+        assert!(Matrix::from("\n\n", |c| c).is_err());
+    }
+}
@@ -0,0 +1,53 @@
+use crate::AocError;
+use std::str::FromStr;
+
+/// Parses a number, pointing at `token` when it is not one.
+pub fn number<T: FromStr>(token: &str) -> Result<T, AocError> {
+    token.trim().parse().map_err(|_| {
+        AocError::at(token, format!("expected a number, found {token:?}"))
+    })
+}
+
+/// Splits `input` at the first `separator`, e.g. between the two sections of
+/// an input separated by a blank line.
+pub fn split_once<'a>(
+    input: &'a str,
+    separator: &str,
+) -> Result<(&'a str, &'a str), AocError> {
+    input.split_once(separator).ok_or_else(|| {
+        AocError::at(input, format!("expected {separator:?} after this"))
+    })
+}
+
+/// Runs a nom parser on all of `input`, rejecting anything left over.
+pub fn finish<T>(result: nom::IResult<&str, T>) -> Result<T, AocError> {
+    match result {
+        Ok((rest, value)) if rest.trim().is_empty() => Ok(value),
+        Ok((rest, _)) => Err(AocError::at(rest, "unexpected trailing input")),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u8>(" 42"), Ok(42));
+        let input = "1 x";
+        let err = number::<u8>(&input[2..]).unwrap_err();
+        assert_eq!(err.location(input), Some((1, 3)));
+    }
+
+    #[test]
+    fn test_finish() {
+        let digits = nom::character::complete::digit1::<&str, _>;
+        assert_eq!(finish(digits("12\n")), Ok("12"));
+        let input = "12ab";
+        let err = finish(digits(input)).unwrap_err();
+        assert_eq!(err.location(input), Some((1, 3)));
+        let err = finish(digits("ab")).unwrap_err();
+        assert!(err.message().starts_with("cannot parse this"));
+    }
+}
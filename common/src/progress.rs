@@ -0,0 +1,42 @@
+use indicatif::{
+    MultiProgress, ProgressBar, ProgressDrawTarget, ProgressFinish,
+    ProgressStyle,
+};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::OnceLock;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Shows the bars from now on. The runner calls this for `-v`; without it
+/// every bar is hidden, so benchmarks and tests stay quiet.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// All bars share one stderr target, so the parts of a parallel run each
+/// get their own line instead of drawing over each other.
+fn bars() -> &'static MultiProgress {
+    static BARS: OnceLock<MultiProgress> = OnceLock::new();
+    BARS.get_or_init(|| {
+        MultiProgress::with_draw_target(ProgressDrawTarget::stderr())
+    })
+}
+
+/// A bar for a loop of `len` steps with an ETA, cleared once dropped. Wrap
+/// the loop's iterator with `bar.wrap_iter(...)` to advance it.
+pub fn bar(len: usize, message: &'static str) -> ProgressBar {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return ProgressBar::hidden();
+    }
+    let style = ProgressStyle::with_template(
+        "{msg} [{bar:30}] {human_pos}/{human_len} {elapsed}, eta {eta}",
+    )
+    .expect("the template is valid")
+    .progress_chars("=> ");
+    bars().add(
+        ProgressBar::new(len as u64)
+            .with_style(style)
+            .with_message(message)
+            .with_finish(ProgressFinish::AndClear),
+    )
+}
@@ -0,0 +1,56 @@
+use crate::input::Source;
+use crate::AocError;
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use std::process;
+
+/// A day's puzzle: the input is parsed once and both parts answer from it.
+/// Malformed input and puzzles without an answer are reported as errors.
+pub trait Solution {
+    type Input<'a>;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError>;
+
+    fn part1(input: &Self::Input<'_>) -> Result<Self::Part1, AocError>;
+
+    fn part2(input: &Self::Input<'_>) -> Result<Self::Part2, AocError>;
+}
+
+/// Answer for a part that has no puzzle (day 25 part 2) or is not solved yet.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct NoAnswer;
+
+impl Display for NoAnswer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "-")
+    }
+}
+
+/// Entry point of a day binary: loads the input picked on the command line
+/// (see `Source::from_args`) and prints both answers.
+pub fn run<S: Solution>(day_dir: &str, embedded: Option<&str>) {
+    let source =
+        Source::from_args(env::args().skip(1)).unwrap_or_else(|err| {
+            eprintln!("{err}");
+            process::exit(2);
+        });
+    let input =
+        source
+            .load(Path::new(day_dir), embedded)
+            .unwrap_or_else(|err| {
+                eprintln!("cannot load input: {err}");
+                process::exit(1);
+            });
+    let answers = S::parse(&input).and_then(|parsed| {
+        println!("{}", S::part1(&parsed)?);
+        println!("{}", S::part2(&parsed)?);
+        Ok(())
+    });
+    if let Err(err) = answers {
+        eprintln!("{}", err.render(&input));
+        process::exit(1);
+    }
+}
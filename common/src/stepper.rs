@@ -0,0 +1,224 @@
+use crate::viz::{Cell, Frame};
+use crate::{AocError, Solution};
+
+/// The state of a simulation after one step, as the step debugger shows
+/// it: a grid, a label for the move or instruction, and lines of text like
+/// registers to show next to the grid.
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub label: String,
+    pub frame: Frame,
+    pub panel: Vec<String>,
+    /// The cell to keep in view when the grid does not fit the screen.
+    pub focus: Option<(usize, usize)>,
+}
+
+impl Snapshot {
+    pub fn new(label: impl Into<String>, frame: Frame) -> Self {
+        Snapshot {
+            label: label.into(),
+            frame,
+            panel: vec![],
+            focus: None,
+        }
+    }
+
+    pub fn with_panel(self, panel: Vec<String>) -> Self {
+        Snapshot { panel, ..self }
+    }
+
+    pub fn with_focus(self, focus: (usize, usize)) -> Self {
+        Snapshot {
+            focus: Some(focus),
+            ..self
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Change {
+    index: usize,
+    before: Cell,
+    after: Cell,
+}
+
+#[derive(Debug, Clone)]
+struct Step {
+    label: String,
+    panel: Vec<String>,
+    focus: Option<(usize, usize)>,
+    /// Cells that differ from the step before.
+    changes: Vec<Change>,
+}
+
+/// Every step of a simulation. Only the cells a step changes are stored,
+/// so the tens of thousands of moves of a big grid stay small, and moving
+/// between steps replays the changes in either direction.
+#[derive(Debug, Clone, Default)]
+pub struct Tape {
+    first: Option<Frame>,
+    last: Option<Frame>,
+    steps: Vec<Step>,
+}
+
+impl Tape {
+    /// Appends a step. Every frame must have the size of the first one.
+    pub fn record(&mut self, snapshot: Snapshot) {
+        let Snapshot {
+            label,
+            frame,
+            panel,
+            focus,
+        } = snapshot;
+        let changes = match &self.last {
+            None => vec![],
+            Some(last) => {
+                assert_eq!(
+                    (last.height, last.width),
+                    (frame.height, frame.width),
+                    "every frame of a tape has the same size"
+                );
+                last.cells()
+                    .iter()
+                    .zip(frame.cells())
+                    .enumerate()
+                    .filter(|(_, (before, after))| before != after)
+                    .map(|(index, (&before, &after))| Change {
+                        index,
+                        before,
+                        after,
+                    })
+                    .collect()
+            }
+        };
+        if self.first.is_none() {
+            self.first = Some(frame.clone());
+        }
+        self.last = Some(frame);
+        self.steps.push(Step {
+            label,
+            panel,
+            focus,
+            changes,
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// A cursor on the first step, or `None` for an empty tape.
+    pub fn cursor(&self) -> Option<Cursor<'_>> {
+        Some(Cursor {
+            tape: self,
+            frame: self.first.clone()?,
+            position: 0,
+        })
+    }
+}
+
+/// A position on a [`Tape`] with the grid as it is at that step.
+pub struct Cursor<'a> {
+    tape: &'a Tape,
+    frame: Frame,
+    position: usize,
+}
+
+impl Cursor<'_> {
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn frame(&self) -> &Frame {
+        &self.frame
+    }
+
+    pub fn label(&self) -> &str {
+        &self.step().label
+    }
+
+    pub fn panel(&self) -> &[String] {
+        &self.step().panel
+    }
+
+    pub fn focus(&self) -> Option<(usize, usize)> {
+        self.step().focus
+    }
+
+    fn step(&self) -> &Step {
+        &self.tape.steps[self.position]
+    }
+
+    /// Moves to step `target`, or the last one if there are fewer.
+    pub fn goto(&mut self, target: usize) {
+        let target = target.min(self.tape.len() - 1);
+        while self.position < target {
+            self.position += 1;
+            for change in &self.tape.steps[self.position].changes {
+                self.frame.cells_mut()[change.index] = change.after;
+            }
+        }
+        while self.position > target {
+            for change in &self.tape.steps[self.position].changes {
+                self.frame.cells_mut()[change.index] = change.before;
+            }
+            self.position -= 1;
+        }
+    }
+
+    /// Moves by `delta` steps, stopping at either end.
+    pub fn step_by(&mut self, delta: isize) {
+        self.goto(self.position.saturating_add_signed(delta));
+    }
+}
+
+/// A day whose simulation can be stepped through with `aoc debug`.
+pub trait StepThrough: Solution {
+    /// Records every step of `part`, 1 or 2, onto `tape`.
+    fn step_through(
+        input: &Self::Input<'_>,
+        part: u8,
+        tape: &mut Tape,
+    ) -> Result<(), AocError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(s: &str) -> Snapshot {
+        let chars: Vec<char> = s.chars().collect();
+        let frame =
+            Frame::from_fn(1, chars.len(), |_, j| Cell::from(chars[j]));
+        Snapshot::new(s, frame)
+    }
+
+    #[test]
+    fn test_cursor() {
+        let mut tape = Tape::default();
+        assert!(tape.cursor().is_none());
+        for s in ["@..", ".@.", "..@", ".@."] {
+            tape.record(line(s));
+        }
+        let text = |cursor: &Cursor| -> String {
+            (0..3).map(|j| cursor.frame().get(0, j).ch).collect()
+        };
+
+        let mut cursor = tape.cursor().unwrap();
+        assert_eq!(text(&cursor), "@..");
+        cursor.goto(2);
+        assert_eq!(text(&cursor), "..@");
+        assert_eq!(cursor.label(), "..@");
+        cursor.step_by(-2);
+        assert_eq!(text(&cursor), "@..");
+        cursor.goto(100);
+        assert_eq!(cursor.position(), 3);
+        assert_eq!(text(&cursor), ".@.");
+        cursor.step_by(-5);
+        assert_eq!(cursor.position(), 0);
+    }
+}
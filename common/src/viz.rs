@@ -0,0 +1,396 @@
+use crate::{AocError, Matrix, Solution};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+pub type Color = [u8; 3];
+
+pub const RED: Color = [220, 50, 47];
+pub const GREEN: Color = [133, 153, 0];
+pub const YELLOW: Color = [181, 137, 0];
+pub const BLUE: Color = [38, 139, 210];
+pub const GRAY: Color = [128, 128, 128];
+pub const BLACK: Color = [0, 0, 0];
+
+/// Colors for characters a day did not pick one for.
+const PALETTE: [Color; 6] =
+    [RED, GREEN, YELLOW, BLUE, [211, 54, 130], [42, 161, 152]];
+
+/// Frames a recording keeps unless told otherwise.
+pub const DEFAULT_LIMIT: usize = 500;
+
+/// One cell of a frame: the character shown in the terminal and an optional
+/// color. Cells without a color get one picked from their character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub ch: char,
+    pub color: Option<Color>,
+}
+
+impl Cell {
+    pub fn colored(ch: char, color: Color) -> Self {
+        Cell {
+            ch,
+            color: Some(color),
+        }
+    }
+
+    pub fn rgb(&self) -> Color {
+        self.color.unwrap_or(match self.ch {
+            '#' => GRAY,
+            '.' | ' ' => BLACK,
+            ch => PALETTE[ch as usize % PALETTE.len()],
+        })
+    }
+}
+
+impl From<char> for Cell {
+    fn from(ch: char) -> Self {
+        Cell { ch, color: None }
+    }
+}
+
+/// A snapshot of a grid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub height: usize,
+    pub width: usize,
+    cells: Vec<Cell>,
+}
+
+impl Frame {
+    pub fn from_fn(
+        height: usize,
+        width: usize,
+        cell: impl Fn(usize, usize) -> Cell,
+    ) -> Self {
+        let cells = (0..height)
+            .flat_map(|row| (0..width).map(move |col| (row, col)))
+            .map(|(row, col)| cell(row, col))
+            .collect();
+        Frame {
+            height,
+            width,
+            cells,
+        }
+    }
+
+    /// Takes the width from the first row; shorter rows are padded with
+    /// blanks.
+    pub fn from_rows<T>(rows: &[Vec<T>], cell: impl Fn(&T) -> Cell) -> Self {
+        let width = rows.first().map_or(0, Vec::len);
+        Frame::from_fn(rows.len(), width, |row, col| {
+            rows[row].get(col).map_or(Cell::from(' '), &cell)
+        })
+    }
+
+    pub fn from_matrix<T>(
+        matrix: &Matrix<T>,
+        cell: impl Fn(&T) -> Cell,
+    ) -> Self {
+        Frame::from_rows(&matrix.matrix, cell)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Cell {
+        self.cells[row * self.width + col]
+    }
+
+    pub fn set(&mut self, row: usize, col: usize, cell: impl Into<Cell>) {
+        self.cells[row * self.width + col] = cell.into();
+    }
+
+    /// The cells row by row.
+    pub(crate) fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    pub(crate) fn cells_mut(&mut self) -> &mut [Cell] {
+        &mut self.cells
+    }
+
+    /// The frame as RGB bytes, each cell `scale` pixels wide and high.
+    fn pixels(&self, scale: usize) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(self.cells.len() * scale * 3);
+        for row in self.cells.chunks(self.width.max(1)) {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|cell| cell.rgb().repeat(scale))
+                .collect();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&line);
+            }
+        }
+        pixels
+    }
+}
+
+/// Frames of a simulation, capped at a limit: once there are more, every
+/// other frame is dropped and from then on only every other step is kept,
+/// so a long run ends up as evenly spaced frames in bounded memory.
+#[derive(Debug, Clone)]
+pub struct Recording {
+    frames: Vec<Frame>,
+    limit: usize,
+    stride: usize,
+    steps: usize,
+}
+
+impl Default for Recording {
+    fn default() -> Self {
+        Recording::with_limit(DEFAULT_LIMIT)
+    }
+}
+
+impl Recording {
+    pub fn with_limit(limit: usize) -> Self {
+        Recording {
+            frames: vec![],
+            limit: limit.max(2),
+            stride: 1,
+            steps: 0,
+        }
+    }
+
+    /// Offers the next step. The frame is only built if the step is kept,
+    /// so recording every step of a long loop stays cheap.
+    pub fn record(&mut self, frame: impl FnOnce() -> Frame) {
+        if self.steps.is_multiple_of(self.stride) {
+            self.frames.push(frame());
+            if self.frames.len() > self.limit {
+                let mut index = 0;
+                self.frames.retain(|_| {
+                    index += 1;
+                    index % 2 == 1
+                });
+                self.stride *= 2;
+            }
+        }
+        self.steps += 1;
+    }
+
+    /// Adds the final state, which thinning must not drop.
+    pub fn finish(&mut self, frame: Frame) {
+        if self.frames.last() != Some(&frame) {
+            self.frames.push(frame);
+        }
+    }
+
+    pub fn frames(&self) -> &[Frame] {
+        &self.frames
+    }
+
+    /// Writes an animated GIF that loops forever. The palette holds the
+    /// colors of all frames, so there can be at most 256 of them.
+    pub fn write_gif(
+        &self,
+        out: impl Write,
+        scale: usize,
+        delay: Duration,
+    ) -> io::Result<()> {
+        let size = |cells: usize| {
+            u16::try_from(cells * scale).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "the frames are too large for a GIF",
+                )
+            })
+        };
+        let width = self.frames.iter().map(|f| f.width).max().unwrap_or(0);
+        let height = self.frames.iter().map(|f| f.height).max().unwrap_or(0);
+        let (width, height) = (size(width)?, size(height)?);
+
+        let mut palette: HashMap<Color, u8> = HashMap::new();
+        let mut colors: Vec<u8> = vec![];
+        for cell in self.frames.iter().flat_map(|frame| &frame.cells) {
+            let rgb = cell.rgb();
+            if palette.contains_key(&rgb) {
+                continue;
+            }
+            let index = u8::try_from(palette.len()).map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    "a GIF has room for 256 colors",
+                )
+            })?;
+            palette.insert(rgb, index);
+            colors.extend(rgb);
+        }
+
+        let mut encoder = gif::Encoder::new(out, width, height, &colors)
+            .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        let delay = u16::try_from(delay.as_millis() / 10).unwrap_or(u16::MAX);
+        for frame in &self.frames {
+            let indices: Vec<u8> = frame
+                .pixels(scale)
+                .chunks(3)
+                .map(|rgb| palette[&[rgb[0], rgb[1], rgb[2]]])
+                .collect();
+            let frame = gif::Frame {
+                width: size(frame.width)?,
+                height: size(frame.height)?,
+                delay,
+                buffer: Cow::Owned(indices),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    /// Writes one numbered image per frame into `dir` and returns their
+    /// paths.
+    pub fn write_images(
+        &self,
+        dir: &Path,
+        format: ImageFormat,
+        scale: usize,
+    ) -> io::Result<Vec<PathBuf>> {
+        fs::create_dir_all(dir)?;
+        let mut written = vec![];
+        for (i, frame) in self.frames.iter().enumerate() {
+            let path = dir.join(format!("frame{i:05}.{}", format.extension()));
+            let mut out = io::BufWriter::new(fs::File::create(&path)?);
+            match format {
+                ImageFormat::Ppm => write_ppm(&mut out, frame, scale)?,
+                ImageFormat::Png => write_png(&mut out, frame, scale)?,
+            }
+            out.flush()?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+
+    /// Plays the frames in the terminal, redrawing in place with true color
+    /// escapes for the cells that have a color of their own.
+    pub fn replay_ansi(
+        &self,
+        mut out: impl Write,
+        delay: Duration,
+    ) -> io::Result<()> {
+        write!(out, "\x1b[2J")?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            write!(out, "\x1b[H")?;
+            for row in frame.cells.chunks(frame.width.max(1)) {
+                for cell in row {
+                    match cell.color {
+                        Some([r, g, b]) => write!(
+                            out,
+                            "\x1b[38;2;{r};{g};{b}m{}\x1b[0m",
+                            cell.ch
+                        )?,
+                        None => write!(out, "{}", cell.ch)?,
+                    }
+                }
+                writeln!(out)?;
+            }
+            writeln!(out, "frame {}/{}", i + 1, self.frames.len())?;
+            out.flush()?;
+            thread::sleep(delay);
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+}
+
+impl ImageFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+        }
+    }
+}
+
+fn write_ppm(
+    mut out: impl Write,
+    frame: &Frame,
+    scale: usize,
+) -> io::Result<()> {
+    let (width, height) = (frame.width * scale, frame.height * scale);
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    out.write_all(&frame.pixels(scale))
+}
+
+fn write_png(out: impl Write, frame: &Frame, scale: usize) -> io::Result<()> {
+    let dimension = |cells: usize| {
+        u32::try_from(cells * scale).map_err(|_| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "the frame is too large",
+            )
+        })
+    };
+    let mut encoder = png::Encoder::new(
+        out,
+        dimension(frame.width)?,
+        dimension(frame.height)?,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header().map_err(io::Error::other)?;
+    writer
+        .write_image_data(&frame.pixels(scale))
+        .map_err(io::Error::other)
+}
+
+/// A day that can replay its simulation into a [`Recording`], for
+/// `aoc viz`.
+pub trait Visualize: Solution {
+    fn visualize(
+        input: &Self::Input<'_>,
+        recording: &mut Recording,
+    ) -> Result<(), AocError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(ch: char) -> Frame {
+        Frame::from_fn(2, 3, |_, _| Cell::from(ch))
+    }
+
+    #[test]
+    fn test_record() {
+        let mut recording = Recording::with_limit(4);
+        for step in 0..10 {
+            recording.record(|| frame(char::from(b'0' + step)));
+        }
+        recording.finish(frame('9'));
+        let kept: Vec<char> =
+            recording.frames().iter().map(|f| f.get(0, 0).ch).collect();
+        assert_eq!(kept, ['0', '4', '8', '9']);
+    }
+
+    #[test]
+    fn test_write() {
+        let mut recording = Recording::default();
+        let mut last = frame('.');
+        last.set(1, 2, Cell::colored('@', RED));
+        recording.record(|| frame('#'));
+        recording.finish(last);
+
+        let mut gif = vec![];
+        recording
+            .write_gif(&mut gif, 2, Duration::from_millis(100))
+            .unwrap();
+        assert!(gif.starts_with(b"GIF89a"));
+
+        let mut ppm = vec![];
+        write_ppm(&mut ppm, &recording.frames()[1], 1).unwrap();
+        assert!(ppm.starts_with(b"P6\n3 2\n255\n"));
+        assert_eq!(&ppm[ppm.len() - 3..], &RED);
+    }
+}
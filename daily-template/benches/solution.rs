@@ -0,0 +1,5 @@
+common::solution_benches!({{crate_name}}::{{struct_name}});
+
+fn main() {
+    divan::main();
+}
@@ -0,0 +1,9 @@
+use rand::Rng;
+
+/// A random puzzle input of `size` lines. Until the puzzle is known these
+/// are just numbers; make them look like the real input.
+pub fn input(rng: &mut impl Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| format!("{}\n", rng.gen_range(0..100)))
+        .collect()
+}
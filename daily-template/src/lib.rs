@@ -0,0 +1,43 @@
+use common::{AocError, NoAnswer, Solution};
+
+#[cfg(test)]
+mod generate;
+#[cfg(test)]
+mod reference;
+
+common::embedded_input!();
+
+pub struct {{struct_name}};
+
+impl Solution for {{struct_name}} {
+    type Input<'a> = &'a str;
+    type Part1 = NoAnswer;
+    type Part2 = NoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, AocError> {
+        Ok(input)
+    }
+
+    fn part1(_input: &Self::Input<'_>) -> Result<NoAnswer, AocError> {
+        Ok(NoAnswer)
+    }
+
+    fn part2(_input: &Self::Input<'_>) -> Result<NoAnswer, AocError> {
+        Ok(NoAnswer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests! {
+        {{struct_name}};
+        example: part1, part2;
+    }
+
+    common::generator_tests! {
+        {{struct_name}};
+        max_size: 10;
+    }
+}
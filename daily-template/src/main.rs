@@ -1,30 +1,5 @@
-fn main() {
-    let input = include_str!("input.txt");
-    println!("{:?}", part1(input));
-    println!("{:?}", part2(input));
-}
-
-fn part1(input: &str) {}
-
-fn part2(input: &str) {}
+use {{crate_name}}::{{{struct_name}}, INPUT};
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use indoc::indoc;
-
-    #[test]
-    fn test_part1() {
-        let input = indoc! {
-            r#"
-            "#
-        };
-        // assert_eq!();
-    }
-
-
-    #[test]
-    fn test_part2() {
-        // assert_eq!();
-    }
+fn main() {
+    common::run::<{{struct_name}}>(env!("CARGO_MANIFEST_DIR"), INPUT);
 }
@@ -0,0 +1,7 @@
+use common::differential::Expected;
+
+/// Slow but simple answers to check the solver against. Until there are
+/// any, no part is compared.
+pub fn expected(_input: &str) -> Expected {
+    Expected::default()
+}